@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 
-use elm_rs::{Elm, ElmDecode, ElmEncode, ElmQuery, ElmQueryField};
+use elm_rs::{
+    Elm, ElmDecode, ElmEncode, ElmForm, ElmPath, ElmQuery, ElmQueryField, ElmQueryParser, ElmRoute,
+    Endpoint,
+};
 
 #[derive(Elm, ElmEncode, ElmDecode)]
 enum Filetype {
@@ -16,18 +19,57 @@ struct Drawing {
     filetype: Filetype,
 }
 
-#[derive(Elm, ElmQuery)]
+#[derive(Elm, ElmQuery, ElmQueryParser)]
 struct Query {
     page: usize,
     thumbnail_size: Size,
 }
 
-#[derive(Elm, ElmQueryField)]
+#[derive(Elm, ElmQueryField, ElmQueryParser)]
 enum Size {
     Small,
     Large,
 }
 
+#[derive(ElmPath)]
+#[elm(path = "/drawings/{id}/thumbnail")]
+struct Thumbnail {
+    id: u32,
+    #[elm(query)]
+    query: Query,
+}
+
+#[derive(Elm, ElmRoute)]
+enum Route {
+    #[elm(path = "/")]
+    Home,
+    #[elm(path = "/drawings/:id")]
+    Drawing { id: u32 },
+}
+
+#[derive(Elm, ElmForm)]
+struct Login {
+    username: String,
+    password: String,
+}
+
+#[derive(ElmPath)]
+#[elm(path = "/drawings/{id}")]
+struct DrawingById {
+    id: u32,
+}
+
+struct GetDrawing;
+
+impl Endpoint for GetDrawing {
+    const NAME: &'static str = "getDrawing";
+    const METHOD: &'static str = "GET";
+    type Path = DrawingById;
+    type Body = ();
+    type Response = Drawing;
+    type Error = ();
+}
+
 fn main() {
     // the target would typically be a file
     let mut target = vec![];
@@ -41,6 +83,16 @@ fn main() {
         queries: [Query],
         // generates types and functions for forming queries for types implementing ElmQueryField
         query_fields: [Size],
+        // generates types and functions for parsing queries for types implementing ElmQueryParser
+        query_parsers: [Query, Size],
+        // generates functions for building paths for types implementing ElmPath
+        paths: [Thumbnail, DrawingById],
+        // generates types and functions for client-side routing for types implementing ElmRoute
+        routes: [Route],
+        // generates types and functions for creating form bodies for types implementing ElmForm
+        forms: [Login],
+        // generates functions for sending requests for types implementing Endpoint
+        endpoints: [GetDrawing],
     })
     .unwrap();
     let output = String::from_utf8(target).unwrap();
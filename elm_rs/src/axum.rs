@@ -0,0 +1,605 @@
+//! Integration with axum. `ElmRouter` wraps `axum::Router` and records the method, path and handler of each route
+//! so that `export!` can generate Elm functions that send requests to them.
+
+use crate::{
+    endpoint::{RequestFunction, ResponseBody},
+    settings::Settings,
+    Elm, ElmDecode, ElmEncode, ElmForm, ElmQuery, ElmQueryField,
+};
+use ::axum::{
+    extract::{Extension, Form, Path, Query, State},
+    handler::Handler,
+    http::{HeaderMap, Method, StatusCode, Uri},
+    routing::{self, MethodRouter},
+    Json, Router,
+};
+use std::{collections::BTreeSet, future::Future};
+
+/// Wraps `axum::Router` and records the routes added with `ElmRouter::route`. The router is used with axum through
+/// `ElmRouter::into_router` and exported with the `routers` key of `export!`, which generates an Elm function
+/// for each handler named after the handler function in camel case. The name is taken from `std::any::type_name`,
+/// which isn't guaranteed to be stable, so handlers can be given an explicit name with `get_named` and so on.
+///
+/// Handlers can only be added if their extractors implement `ElmExtractor` and their response implements
+/// `ElmResponse`, so handlers returning `Html`, `Response` or `impl IntoResponse` need to be added to the
+/// `axum::Router` instead, and don't get an Elm function. Layers, fallbacks and other methods of `axum::Router`
+/// can be applied to the result of `into_router`.
+///
+/// # Example
+/// ```
+/// use axum::{extract::Path, Json};
+/// use elm_rs::{
+///     axum::{get, ElmRouter},
+///     Elm, ElmDecode, ElmEncode,
+/// };
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, Elm, ElmEncode, ElmDecode)]
+/// struct Drawing {
+///     title: String,
+/// }
+///
+/// async fn get_drawing(Path(id): Path<u32>) -> Json<Drawing> {
+///     Json(Drawing {
+///         title: id.to_string(),
+///     })
+/// }
+///
+/// async fn update_drawing(Path(id): Path<u32>, Json(drawing): Json<Drawing>) {}
+///
+/// fn app() -> ElmRouter {
+///     ElmRouter::new().route("/drawings/{id}", get(get_drawing).put(update_drawing))
+/// }
+///
+/// let router: axum::Router = app().into_router();
+/// let mut target = vec![];
+/// elm_rs::export!("Bindings", &mut target, {
+///     encoders: [Drawing],
+///     decoders: [Drawing],
+///     routers: [app()],
+/// })
+/// .unwrap();
+/// let output = String::from_utf8(target).unwrap();
+/// assert!(output.contains("getDrawing : Int -> (Result Http.Error Drawing -> msg) -> Cmd msg"));
+/// assert!(output.contains("updateDrawing : Int -> Drawing -> (Result Http.Error () -> msg) -> Cmd msg"));
+/// ```
+pub struct ElmRouter<S = ()> {
+    router: Router<S>,
+    routes: Vec<(String, HandlerDescription)>,
+}
+
+impl<S: Clone + Send + Sync + 'static> ElmRouter<S> {
+    /// Creates an empty router.
+    pub fn new() -> Self {
+        Self {
+            router: Router::new(),
+            routes: Vec::new(),
+        }
+    }
+
+    /// Adds the handlers of the method router to the path like `axum::Router::route`. The path is turned into
+    /// the URL in Elm, with the parameters written as `{id}` taken from the `Path` extractor of each handler.
+    pub fn route(mut self, path: &str, method_router: ElmMethodRouter<S>) -> Self {
+        self.router = self.router.route(path, method_router.router);
+        self.routes.extend(
+            method_router
+                .handlers
+                .into_iter()
+                .map(|handler| (path.to_string(), handler)),
+        );
+        self
+    }
+
+    /// Nests the routes of the other router under the path like `axum::Router::nest`, prefixing the recorded
+    /// paths with it.
+    pub fn nest(mut self, path: &str, other: ElmRouter<S>) -> Self {
+        self.router = self.router.nest(path, other.router);
+        let prefix = path.trim_end_matches('/');
+        self.routes
+            .extend(other.routes.into_iter().map(|(route, handler)| {
+                let route = if route == "/" {
+                    prefix.to_string()
+                } else {
+                    format!("{prefix}{route}")
+                };
+                (route, handler)
+            }));
+        self
+    }
+
+    /// Merges the routes of the other router into this one like `axum::Router::merge`.
+    pub fn merge(mut self, other: ElmRouter<S>) -> Self {
+        self.router = self.router.merge(other.router);
+        self.routes.extend(other.routes);
+        self
+    }
+
+    /// The `axum::Router` with the routes.
+    pub fn into_router(self) -> Router<S> {
+        self.router
+    }
+
+    /// Generates an Elm function for each handler. Used by the `export!` macro.
+    #[doc(hidden)]
+    pub fn elm_endpoints(&self) -> Vec<String> {
+        let mut names = BTreeSet::new();
+        self.routes
+            .iter()
+            .map(|(path, handler)| {
+                let request_function = handler.request_function(path);
+                if !names.insert(request_function.name.clone()) {
+                    Settings::report_error(format!(
+                        "the Elm function `{}` is generated for more than one route",
+                        request_function.name
+                    ));
+                }
+                request_function.to_elm()
+            })
+            .collect()
+    }
+}
+
+impl<S: Clone + Send + Sync + 'static> Default for ElmRouter<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Clone + Send + Sync + 'static> From<ElmRouter<S>> for Router<S> {
+    fn from(router: ElmRouter<S>) -> Self {
+        router.into_router()
+    }
+}
+
+/// Wraps `axum::routing::MethodRouter` and records the handlers for each method.
+pub struct ElmMethodRouter<S = ()> {
+    router: MethodRouter<S>,
+    handlers: Vec<HandlerDescription>,
+}
+
+macro_rules! method_routers {
+    ($($method: ident, $method_named: ident, $name: literal);* $(;)?) => {
+        impl<S: Clone + Send + Sync + 'static> ElmMethodRouter<S> {
+            $(
+                #[doc = concat!("Routes `", $name, "` requests to the handler like `axum::routing::MethodRouter::", stringify!($method), "`.")]
+                pub fn $method<H, T, A>(mut self, handler: H) -> Self
+                where
+                    H: Handler<T, S> + ElmHandler<A>,
+                    T: 'static,
+                {
+                    self.router = self.router.$method(handler);
+                    self.handlers.push(HandlerDescription::new::<H, A>($name, None));
+                    self
+                }
+
+                #[doc = concat!("Like `", stringify!($method), "`, but the Elm function is named `name` instead of being named after the handler.")]
+                pub fn $method_named<H, T, A>(mut self, name: &'static str, handler: H) -> Self
+                where
+                    H: Handler<T, S> + ElmHandler<A>,
+                    T: 'static,
+                {
+                    self.router = self.router.$method(handler);
+                    self.handlers.push(HandlerDescription::new::<H, A>($name, Some(name)));
+                    self
+                }
+            )*
+        }
+
+        $(
+            #[doc = concat!("Routes `", $name, "` requests to the handler like `axum::routing::", stringify!($method), "`.")]
+            pub fn $method<H, T, A, S>(handler: H) -> ElmMethodRouter<S>
+            where
+                H: Handler<T, S> + ElmHandler<A>,
+                T: 'static,
+                S: Clone + Send + Sync + 'static,
+            {
+                ElmMethodRouter {
+                    router: routing::$method(handler),
+                    handlers: vec![HandlerDescription::new::<H, A>($name, None)],
+                }
+            }
+
+            #[doc = concat!("Like `", stringify!($method), "`, but the Elm function is named `name` instead of being named after the handler.")]
+            pub fn $method_named<H, T, A, S>(name: &'static str, handler: H) -> ElmMethodRouter<S>
+            where
+                H: Handler<T, S> + ElmHandler<A>,
+                T: 'static,
+                S: Clone + Send + Sync + 'static,
+            {
+                ElmMethodRouter {
+                    router: routing::$method(handler),
+                    handlers: vec![HandlerDescription::new::<H, A>($name, Some(name))],
+                }
+            }
+        )*
+    };
+}
+
+method_routers! {
+    get, get_named, "GET";
+    post, post_named, "POST";
+    put, put_named, "PUT";
+    patch, patch_named, "PATCH";
+    delete, delete_named, "DELETE";
+}
+
+// the method, handler and optional Elm name of a route, which are turned into an Elm function when exporting
+struct HandlerDescription {
+    method: &'static str,
+    handler: &'static str,
+    name: Option<&'static str>,
+    describe: fn(&mut ElmRequest),
+}
+
+impl HandlerDescription {
+    fn new<H: ElmHandler<A>, A>(method: &'static str, name: Option<&'static str>) -> Self {
+        Self {
+            method,
+            handler: std::any::type_name::<H>(),
+            name,
+            describe: H::describe,
+        }
+    }
+
+    fn request_function(&self, path: &str) -> RequestFunction {
+        let Self {
+            method,
+            handler,
+            name,
+            describe,
+        } = self;
+        let mut request = ElmRequest::default();
+        describe(&mut request);
+        let ElmRequest {
+            path_arguments,
+            path_parameters,
+            query,
+            body,
+            response,
+            error,
+        } = request;
+
+        // without an explicit name the function is named after the handler, which needs to be a function
+        // instead of a closure. `type_name` doesn't guarantee its output, so this is best-effort
+        let handler_name = handler.rsplit("::").next().unwrap_or(handler);
+        let name = if let Some(name) = name {
+            if !name.starts_with(|c: char| c.is_ascii_lowercase())
+                || name.contains(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            {
+                Settings::report_error(format!(
+                    "`{name}` for `{method} {path}` is not a valid name for an Elm function"
+                ));
+            }
+            name.to_string()
+        } else if handler_name.contains(|c: char| !c.is_alphanumeric() && c != '_') {
+            Settings::report_error(format!(
+                "the handler `{handler}` for `{method} {path}` needs to be a function to name the Elm function, or be given a name with `{}_named`",
+                method.to_lowercase()
+            ));
+            "handler".to_string()
+        } else {
+            lower_camel_case(handler_name)
+        };
+
+        let mut segments = Vec::new();
+        let mut unnamed_parameters = path_parameters
+            .iter()
+            .filter(|(name, _)| name.is_none())
+            .map(|(_, value)| value);
+        for segment in path.strip_prefix('/').unwrap_or(path).split('/') {
+            let parameter = segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'));
+            match parameter {
+                Some(name) if name.starts_with('*') => {
+                    Settings::report_error(format!(
+                        "the wildcard `{segment}` in `{path}` is not supported"
+                    ));
+                }
+                Some(name) => {
+                    let value = path_parameters
+                        .iter()
+                        .find(|(parameter, _)| parameter.as_deref() == Some(name))
+                        .map(|(_, value)| value)
+                        .or_else(|| unnamed_parameters.next());
+                    match value {
+                        Some(value) => segments.push(format!("Url.percentEncode ({value})")),
+                        None => Settings::report_error(format!(
+                            "the path parameter `{name}` of `{path}` is not extracted by `{handler}`"
+                        )),
+                    }
+                }
+                None => segments.push(format!("{segment:?}")),
+            }
+        }
+        if unnamed_parameters.next().is_some() {
+            Settings::report_error(format!(
+                "`{handler}` extracts more path parameters than `{path}` has"
+            ));
+        }
+
+        let mut arguments = path_arguments
+            .into_iter()
+            .enumerate()
+            .map(|(index, elm_type)| (format!("elmRsPath{index}"), elm_type))
+            .collect::<Vec<_>>();
+        let query = match query {
+            Some((elm_type, encoder)) => {
+                arguments.push(("elmRsQuery".to_string(), elm_type));
+                format!("({encoder} elmRsQuery)")
+            }
+            None => "[]".to_string(),
+        };
+        let body = body.map(|(elm_type, body)| {
+            arguments.push(("elmRsBody".to_string(), elm_type));
+            body
+        });
+        let segments = if segments.is_empty() {
+            "[]".to_string()
+        } else {
+            format!("[ {} ]", segments.join(", "))
+        };
+
+        RequestFunction {
+            name,
+            method: method.to_string(),
+            arguments,
+            url: format!("Url.Builder.absolute {segments} {query}"),
+            body,
+            response,
+            error,
+        }
+    }
+}
+
+fn lower_camel_case(snake_case: &str) -> String {
+    let mut words = snake_case.split('_').filter(|word| !word.is_empty());
+    let mut camel_case = words.next().unwrap_or_default().to_string();
+    for word in words {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            camel_case.extend(first.to_uppercase());
+            camel_case.push_str(chars.as_str());
+        }
+    }
+    camel_case
+}
+
+/// The parts of the Elm function for a handler, collected from its extractors and response.
+#[derive(Default)]
+pub struct ElmRequest {
+    // the Elm types of the arguments for the path parameters
+    path_arguments: Vec<String>,
+    // the names of the path parameters if known, and the Elm expressions for their values
+    path_parameters: Vec<(Option<String>, String)>,
+    // the Elm type and the query encoder
+    query: Option<(String, String)>,
+    // the Elm type and the expression for the Http.Body
+    body: Option<(String, String)>,
+    // the body of the response, and the Elm type and decoder of the error response
+    response: Option<ResponseBody>,
+    error: Option<(String, String)>,
+}
+
+/// Implemented for handler functions whose arguments implement `ElmExtractor` and whose response
+/// implements `ElmResponse`.
+pub trait ElmHandler<A> {
+    /// Adds the extractors and the response of the handler to the request.
+    fn describe(request: &mut ElmRequest);
+}
+
+macro_rules! impl_handler {
+    ($($ty: ident $marker: ident),*) => {
+        impl<F, Fut, $($ty, $marker,)*> ElmHandler<($(($ty, $marker),)*)> for F
+        where
+            F: FnOnce($($ty),*) -> Fut,
+            Fut: Future,
+            Fut::Output: ElmResponse,
+            $($ty: ElmExtractor<$marker>,)*
+        {
+            #[allow(unused_variables)]
+            fn describe(request: &mut ElmRequest) {
+                $(
+                    $ty::describe_extractor(request);
+                )*
+                Fut::Output::describe_response(request);
+            }
+        }
+    };
+}
+
+impl_handler!();
+impl_handler!(T1 M1);
+impl_handler!(T1 M1, T2 M2);
+impl_handler!(T1 M1, T2 M2, T3 M3);
+impl_handler!(T1 M1, T2 M2, T3 M3, T4 M4);
+impl_handler!(T1 M1, T2 M2, T3 M3, T4 M4, T5 M5);
+impl_handler!(T1 M1, T2 M2, T3 M3, T4 M4, T5 M5, T6 M6);
+impl_handler!(T1 M1, T2 M2, T3 M3, T4 M4, T5 M5, T6 M6, T7 M7);
+impl_handler!(T1 M1, T2 M2, T3 M3, T4 M4, T5 M5, T6 M6, T7 M7, T8 M8);
+
+/// Used for the arguments of handlers. `Path`, `Query`, `Json` and `Form` become arguments of the Elm function,
+/// while extractors that don't depend on the request sent from Elm, such as `State`, can use the default implementation.
+/// The marker `M` only tells apart the implementations for `Path`, custom extractors can leave it out.
+pub trait ElmExtractor<M = ()> {
+    /// Adds the extractor to the request.
+    fn describe_extractor(_request: &mut ElmRequest) {}
+}
+
+impl<T: ElmPathParameters<M>, M> ElmExtractor<M> for Path<T> {
+    fn describe_extractor(request: &mut ElmRequest) {
+        request.path_arguments = T::path_arguments();
+        request.path_parameters = T::path_parameters();
+    }
+}
+
+impl<T: Elm + ElmQuery> ElmExtractor for Query<T> {
+    fn describe_extractor(request: &mut ElmRequest) {
+        request.query = Some((T::elm_type(), T::query_encoder_type()));
+    }
+}
+
+impl<T: Elm + ElmEncode> ElmExtractor for Json<T> {
+    fn describe_extractor(request: &mut ElmRequest) {
+        request.body = Some((
+            T::elm_type(),
+            format!("Http.jsonBody ({} elmRsBody)", T::encoder_type()),
+        ));
+    }
+}
+
+impl<T: Elm + ElmForm> ElmExtractor for Form<T> {
+    fn describe_extractor(request: &mut ElmRequest) {
+        if T::form_has_files() {
+            Settings::report_error(format!(
+                "`{}` has file fields, which can't be used with `axum::Form`",
+                T::elm_type()
+            ));
+        }
+        request.body = Some((
+            T::elm_type(),
+            format!("{} elmRsBody", T::url_encoded_body_type()),
+        ));
+    }
+}
+
+impl<S> ElmExtractor for State<S> {}
+impl<T> ElmExtractor for Extension<T> {}
+impl ElmExtractor for HeaderMap {}
+impl ElmExtractor for Method {}
+impl ElmExtractor for Uri {}
+
+/// Used for the responses of handlers. `Json` responses are decoded in Elm, `String` responses are passed on
+/// with `Http.expectString` and the errors of `Result`s are decoded
+/// into an `EndpointError` if they're `Json` as well.
+pub trait ElmResponse {
+    /// Adds the response to the request.
+    fn describe_response(request: &mut ElmRequest);
+}
+
+impl ElmResponse for () {
+    fn describe_response(_request: &mut ElmRequest) {}
+}
+
+impl ElmResponse for StatusCode {
+    fn describe_response(_request: &mut ElmRequest) {}
+}
+
+impl<T: Elm + ElmDecode> ElmResponse for Json<T> {
+    fn describe_response(request: &mut ElmRequest) {
+        request.response = Some(ResponseBody::Json(T::elm_type(), T::decoder_type()));
+    }
+}
+
+impl ElmResponse for String {
+    fn describe_response(request: &mut ElmRequest) {
+        request.response = Some(ResponseBody::Text);
+    }
+}
+
+impl ElmResponse for &'static str {
+    fn describe_response(request: &mut ElmRequest) {
+        request.response = Some(ResponseBody::Text);
+    }
+}
+
+impl<T: ElmResponse> ElmResponse for (StatusCode, T) {
+    fn describe_response(request: &mut ElmRequest) {
+        T::describe_response(request);
+    }
+}
+
+impl<T: ElmResponse, E: ElmResponse> ElmResponse for Result<T, E> {
+    fn describe_response(request: &mut ElmRequest) {
+        T::describe_response(request);
+        let mut error = ElmRequest::default();
+        E::describe_response(&mut error);
+        // only JSON errors are decoded, others are reported as an `Http.Error`
+        request.error = match error.response {
+            Some(ResponseBody::Json(elm_type, decoder)) => Some((elm_type, decoder)),
+            _ => None,
+        };
+    }
+}
+
+/// Used for the values extracted with `Path`. Values that implement `ElmQueryField` and tuples of them become
+/// arguments of the Elm function and are matched with the path parameters in order, while structs that implement
+/// `ElmQuery` become a record argument and their fields are matched with the path parameters by name.
+/// The marker `M` is `RecordParameters` or `ValueParameters` to keep the implementations apart.
+pub trait ElmPathParameters<M> {
+    /// The Elm types of the arguments, which are named `elmRsPath0`, `elmRsPath1` and so on.
+    fn path_arguments() -> Vec<String>;
+    /// The names of the parameters if they're known and Elm expressions that turn the arguments into strings.
+    fn path_parameters() -> Vec<(Option<String>, String)>;
+}
+
+/// Marks the `ElmPathParameters` implementation for types that implement `ElmQuery`.
+pub enum RecordParameters {}
+
+/// Marks the `ElmPathParameters` implementation for types that implement `ElmQueryField` and tuples of them.
+pub enum ValueParameters {}
+
+impl<T: Elm + ElmQuery> ElmPathParameters<RecordParameters> for T {
+    fn path_arguments() -> Vec<String> {
+        vec![T::elm_type()]
+    }
+
+    fn path_parameters() -> Vec<(Option<String>, String)> {
+        T::query_fields("elmRsPath0")
+            .into_iter()
+            .filter_map(|(field, value)| {
+                let parameter = field.to_path_parameter(&value);
+                if parameter.is_none() {
+                    Settings::report_error(format!(
+                        "the fields of `{}` need to have single values to be path parameters",
+                        T::elm_type()
+                    ));
+                }
+                parameter
+            })
+            .map(|(name, value)| (Some(name), value))
+            .collect()
+    }
+}
+
+impl<T: Elm + ElmQueryField> ElmPathParameters<ValueParameters> for T {
+    fn path_arguments() -> Vec<String> {
+        vec![T::elm_type()]
+    }
+
+    fn path_parameters() -> Vec<(Option<String>, String)> {
+        vec![(None, path_parameter::<T>(0))]
+    }
+}
+
+// an Elm expression that turns the argument into the value of the path parameter
+fn path_parameter<T: Elm + ElmQueryField>(index: usize) -> String {
+    if T::query_field_optional() || T::query_field_list() || T::query_field_file() {
+        Settings::report_error(format!(
+            "`{}` needs to have a single value to be a path parameter",
+            T::elm_type()
+        ));
+    }
+    format!("{} elmRsPath{index}", T::query_field_encoder())
+}
+
+macro_rules! impl_path_parameters {
+    ($($ty: ident $index: literal),*) => {
+        impl<$($ty: Elm + ElmQueryField),*> ElmPathParameters<ValueParameters> for ($($ty,)*) {
+            fn path_arguments() -> Vec<String> {
+                vec![$($ty::elm_type()),*]
+            }
+
+            fn path_parameters() -> Vec<(Option<String>, String)> {
+                vec![$((None, path_parameter::<$ty>($index))),*]
+            }
+        }
+    };
+}
+
+impl_path_parameters!(T0 0);
+impl_path_parameters!(T0 0, T1 1);
+impl_path_parameters!(T0 0, T1 1, T2 2);
+impl_path_parameters!(T0 0, T1 1, T2 2, T3 3);
+impl_path_parameters!(T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_path_parameters!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
@@ -1,5 +1,6 @@
 //! Contains the `Elm` trait.
 
+use crate::{posix, settings::large_integer, ElmMapKey};
 #[cfg(feature = "derive")]
 pub use elm_rs_derive::Elm;
 
@@ -58,6 +59,100 @@ where
     }
 }
 
+// Elm only has tuples up to three elements, so larger tuples are represented as records
+macro_rules! impl_tuple {
+    ($elm_name: expr, $($t: ident: $type_variable: expr),+) => {
+        impl<$($t: Elm),+> Elm for ($($t,)+) {
+            fn elm_type() -> String {
+                ::std::format!(
+                    "{} {}",
+                    $elm_name,
+                    [$(::std::format!("({})", $t::elm_type())),+].join(" ")
+                )
+            }
+
+            fn elm_definition() -> Option<String> {
+                let type_variables = [$($type_variable),+];
+                Some(::std::format!(
+                    "\
+type alias {} {} =
+    {{ {}
+    }}
+",
+                    $elm_name,
+                    type_variables.join(" "),
+                    type_variables
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, type_variable)| ::std::format!("t{idx} : {type_variable}"))
+                        .collect::<Vec<_>>()
+                        .join("\n    , ")
+                ))
+            }
+        }
+    };
+}
+
+impl_tuple!("Tuple4", T0: "a", T1: "b", T2: "c", T3: "d");
+impl_tuple!("Tuple5", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e");
+impl_tuple!("Tuple6", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e", T5: "f");
+impl_tuple!("Tuple7", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e", T5: "f", T6: "g");
+impl_tuple!("Tuple8", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e", T5: "f", T6: "g", T7: "h");
+impl_tuple!(
+    "Tuple9",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i"
+);
+impl_tuple!(
+    "Tuple10",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j"
+);
+impl_tuple!(
+    "Tuple11",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j",
+    T10: "k"
+);
+impl_tuple!(
+    "Tuple12",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j",
+    T10: "k",
+    T11: "l"
+);
+
 impl<T: Elm + ToOwned + ?Sized> Elm for std::borrow::Cow<'_, T> {
     fn elm_type() -> String {
         T::elm_type()
@@ -68,6 +163,28 @@ impl<T: Elm + ToOwned + ?Sized> Elm for std::borrow::Cow<'_, T> {
     }
 }
 
+// serialized transparently as the inner value
+impl<T: Elm> Elm for std::num::Wrapping<T> {
+    fn elm_type() -> String {
+        T::elm_type()
+    }
+
+    fn elm_definition() -> Option<String> {
+        T::elm_definition()
+    }
+}
+
+// serialized transparently as the inner value
+impl<T: Elm> Elm for std::cmp::Reverse<T> {
+    fn elm_type() -> String {
+        T::elm_type()
+    }
+
+    fn elm_definition() -> Option<String> {
+        T::elm_definition()
+    }
+}
+
 impl<T, const U: usize> Elm for [T; U]
 where
     T: Elm,
@@ -109,12 +226,48 @@ impl<T: Elm, E: Elm> Elm for Result<T, E> {
     }
 }
 
+impl<T: Elm> Elm for std::ops::Bound<T> {
+    fn elm_type() -> String {
+        ::std::format!("Bound ({})", T::elm_type())
+    }
+
+    fn elm_definition() -> Option<String> {
+        Some(
+            "\
+type Bound a
+    = Included a
+    | Excluded a
+    | Unbounded
+"
+            .to_string(),
+        )
+    }
+}
+
+// serialized as a unit, skipped in derived records
+impl<T: ?Sized> Elm for std::marker::PhantomData<T> {
+    fn elm_type() -> String {
+        <()>::elm_type()
+    }
+
+    fn elm_definition() -> Option<String> {
+        <()>::elm_definition()
+    }
+}
+
 impl Elm for std::time::SystemTime {
     fn elm_type() -> String {
-        "SystemTime".to_string()
+        if posix::enabled() {
+            "Time.Posix".to_string()
+        } else {
+            "SystemTime".to_string()
+        }
     }
 
     fn elm_definition() -> Option<String> {
+        if posix::enabled() {
+            return None;
+        }
         Some(
             "\
 type alias SystemTime =
@@ -127,6 +280,21 @@ type alias SystemTime =
     }
 }
 
+// the representation depends on Settings::large_integers
+macro_rules! impl_large_integer {
+    ($rust_type: ty) => {
+        impl Elm for $rust_type {
+            fn elm_type() -> String {
+                large_integer::elm_type(stringify!($rust_type))
+            }
+
+            fn elm_definition() -> Option<String> {
+                None
+            }
+        }
+    };
+}
+
 macro_rules! impl_builtin {
     ($rust_type: ty, $elm_type: expr) => {
         impl Elm for $rust_type {
@@ -157,9 +325,18 @@ macro_rules! impl_builtin_container {
 
 macro_rules! impl_builtin_map {
     ($rust_type: ty) => {
-        impl<T: Elm> Elm for $rust_type {
+        impl_builtin_map!($rust_type, K::comparable());
+    };
+    // $dict decides whether the map is represented as a Dict
+    ($rust_type: ty, $dict: expr) => {
+        impl<K: ElmMapKey, T: Elm> Elm for $rust_type {
             fn elm_type() -> String {
-                ::std::format!("Dict String ({})", T::elm_type())
+                if $dict {
+                    ::std::format!("Dict ({}) ({})", K::elm_type(), T::elm_type())
+                } else {
+                    // only comparable types can be used as Dict keys
+                    ::std::format!("List ( {}, {} )", K::elm_type(), T::elm_type())
+                }
             }
 
             fn elm_definition() -> Option<String> {
@@ -169,6 +346,28 @@ macro_rules! impl_builtin_map {
     };
 }
 
+macro_rules! impl_builtin_range {
+    ($rust_type: ty, $elm_name: expr) => {
+        impl<T: Elm> Elm for $rust_type {
+            fn elm_type() -> String {
+                ::std::format!("{} ({})", $elm_name, T::elm_type())
+            }
+
+            fn elm_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+type alias {} a =
+    {{ start : a
+    , end : a
+    }}
+",
+                    $elm_name
+                ))
+            }
+        }
+    };
+}
+
 macro_rules! impl_builtin_ptr {
     ($rust_type: ty) => {
         impl<T: Elm + ?Sized> Elm for $rust_type {
@@ -191,58 +390,66 @@ impl_builtin!(std::sync::atomic::AtomicBool, "Bool");
 impl_builtin!(std::sync::atomic::AtomicU8, "Int");
 impl_builtin!(std::sync::atomic::AtomicU16, "Int");
 impl_builtin!(std::sync::atomic::AtomicU32, "Int");
-impl_builtin!(std::sync::atomic::AtomicU64, "Int");
+impl_large_integer!(std::sync::atomic::AtomicU64);
 impl_builtin!(std::sync::atomic::AtomicUsize, "Int");
 impl_builtin!(std::sync::atomic::AtomicI8, "Int");
 impl_builtin!(std::sync::atomic::AtomicI16, "Int");
 impl_builtin!(std::sync::atomic::AtomicI32, "Int");
-impl_builtin!(std::sync::atomic::AtomicI64, "Int");
+impl_large_integer!(std::sync::atomic::AtomicI64);
 impl_builtin!(std::sync::atomic::AtomicIsize, "Int");
-impl_builtin_map!(std::collections::BTreeMap<String,T>);
+impl_builtin_map!(std::collections::BTreeMap<K, T>);
 impl_builtin_container!(std::collections::BTreeSet<T>, "List");
+impl_builtin_container!(std::collections::BinaryHeap<T>, "List");
 impl_builtin_ptr!(Box<T>);
 impl_builtin_ptr!(std::cell::Cell<T>);
-impl_builtin_map!(std::collections::HashMap<String,T>);
+impl_builtin_map!(std::collections::HashMap<K, T>);
 impl_builtin_container!(std::collections::HashSet<T>, "List");
-// todo ipaddrs
+impl_builtin!(std::net::IpAddr, "String");
+impl_builtin!(std::net::Ipv4Addr, "String");
+impl_builtin!(std::net::Ipv6Addr, "String");
 impl_builtin_container!(std::collections::LinkedList<T>, "List");
 impl_builtin_ptr!(std::sync::Mutex<T>);
 impl_builtin!(std::num::NonZeroU8, "Int");
 impl_builtin!(std::num::NonZeroU16, "Int");
 impl_builtin!(std::num::NonZeroU32, "Int");
-impl_builtin!(std::num::NonZeroU64, "Int");
-impl_builtin!(std::num::NonZeroU128, "Int");
+impl_large_integer!(std::num::NonZeroU64);
+impl_large_integer!(std::num::NonZeroU128);
 impl_builtin!(std::num::NonZeroUsize, "Int");
 impl_builtin!(std::num::NonZeroI8, "Int");
 impl_builtin!(std::num::NonZeroI16, "Int");
 impl_builtin!(std::num::NonZeroI32, "Int");
-impl_builtin!(std::num::NonZeroI64, "Int");
-impl_builtin!(std::num::NonZeroI128, "Int");
+impl_large_integer!(std::num::NonZeroI64);
+impl_large_integer!(std::num::NonZeroI128);
 impl_builtin!(std::num::NonZeroIsize, "Int");
 
 impl_builtin_container!(Option<T>, "Maybe");
 impl_builtin!(std::path::Path, "String");
 impl_builtin!(std::path::PathBuf, "String");
-// todo phantomdata
+impl_builtin_range!(std::ops::Range<T>, "Range");
+impl_builtin_range!(std::ops::RangeInclusive<T>, "RangeInclusive");
 impl_builtin_ptr!(std::rc::Rc<T>);
 impl_builtin_ptr!(std::cell::RefCell<T>);
 impl_builtin_ptr!(std::sync::RwLock<T>);
-// todo socketaddrs
+impl_builtin!(std::net::SocketAddr, "String");
+impl_builtin!(std::net::SocketAddrV4, "String");
+impl_builtin!(std::net::SocketAddrV6, "String");
 impl_builtin!(String, "String");
 impl_builtin_container!(Vec<T>, "List");
+impl_builtin_container!(std::collections::VecDeque<T>, "List");
 impl_builtin_container!([T], "List");
 impl_builtin!(bool, "Bool");
+impl_builtin!(char, "Char");
 impl_builtin!(u8, "Int");
 impl_builtin!(u16, "Int");
 impl_builtin!(u32, "Int");
-impl_builtin!(u64, "Int");
-impl_builtin!(u128, "Int");
+impl_large_integer!(u64);
+impl_large_integer!(u128);
 impl_builtin!(usize, "Int");
 impl_builtin!(i8, "Int");
 impl_builtin!(i16, "Int");
 impl_builtin!(i32, "Int");
-impl_builtin!(i64, "Int");
-impl_builtin!(i128, "Int");
+impl_large_integer!(i64);
+impl_large_integer!(i128);
 impl_builtin!(isize, "Int");
 impl_builtin!(f32, "Float");
 impl_builtin!(f64, "Float");
@@ -251,19 +458,260 @@ impl_builtin!(str, "String");
 #[cfg(feature = "uuid")]
 impl_builtin!(uuid::Uuid, "String");
 
+#[cfg(feature = "url")]
+impl_builtin!(url::Url, "String");
+#[cfg(feature = "semver")]
+impl_builtin!(semver::Version, "String");
+#[cfg(feature = "semver")]
+impl_builtin!(semver::VersionReq, "String");
+// mime and http don't implement serde, so their representations follow Display and the http-serde crate
+#[cfg(feature = "mime")]
+impl_builtin!(mime::Mime, "String");
+#[cfg(feature = "http")]
+impl_builtin!(http::Method, "String");
+#[cfg(feature = "http")]
+impl_builtin!(http::Uri, "String");
+#[cfg(feature = "http")]
+impl_builtin!(http::StatusCode, "Int");
+
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::Value, "Json.Encode.Value");
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::Map<String, serde_json::Value>, "Json.Encode.Value");
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::value::RawValue, "Json.Encode.Value");
+
+#[cfg(feature = "indexmap")]
+impl_builtin_map!(
+    indexmap::IndexMap<K, T>,
+    K::comparable() && !crate::Settings::current().ordered_index_maps
+);
+#[cfg(feature = "indexmap")]
+impl_builtin_container!(indexmap::IndexSet<T>, "List");
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Elm for smallvec::SmallVec<A>
+where
+    A::Item: Elm,
+{
+    fn elm_type() -> String {
+        Vec::<A::Item>::elm_type()
+    }
+
+    fn elm_definition() -> Option<String> {
+        Vec::<A::Item>::elm_definition()
+    }
+}
+#[cfg(feature = "arrayvec")]
+impl<T: Elm, const CAP: usize> Elm for arrayvec::ArrayVec<T, CAP> {
+    fn elm_type() -> String {
+        Vec::<T>::elm_type()
+    }
+
+    fn elm_definition() -> Option<String> {
+        Vec::<T>::elm_definition()
+    }
+}
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> Elm for arrayvec::ArrayString<CAP> {
+    fn elm_type() -> String {
+        String::elm_type()
+    }
+
+    fn elm_definition() -> Option<String> {
+        String::elm_definition()
+    }
+}
+// serde_json serializes bytes as arrays of integers
+#[cfg(feature = "bytes")]
+impl_builtin!(bytes::Bytes, "List (Int)");
+#[cfg(feature = "bytes")]
+impl_builtin!(bytes::BytesMut, "List (Int)");
+#[cfg(feature = "serde_bytes")]
+impl_builtin!(serde_bytes::ByteBuf, "List (Int)");
+#[cfg(feature = "serde_bytes")]
+impl_builtin!(serde_bytes::Bytes, "List (Int)");
+
+#[cfg(any(
+    all(feature = "time", not(feature = "time-serde-human-readable")),
+    feature = "chrono"
+))]
+macro_rules! impl_time_tuple {
+    ($rust_type: ty, $elm_type: expr, $($field: expr),+) => {
+        impl Elm for $rust_type {
+            fn elm_type() -> String {
+                $elm_type.to_string()
+            }
+
+            fn elm_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+type alias {} =
+    {{ {}
+    }}
+",
+                    $elm_type,
+                    [$(::std::format!("{} : Int", $field)),+].join("\n    , ")
+                ))
+            }
+        }
+    };
+}
+
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveTime, "String");
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveDate, "String");
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveDateTime, "String");
+// FixedOffset doesn't implement serde, so its representation follows Display
+#[cfg(feature = "chrono")]
+impl_builtin!(chrono::FixedOffset, "String");
 #[cfg(feature = "chrono")]
 impl<T: chrono::TimeZone> Elm for chrono::DateTime<T> {
     fn elm_type() -> String {
-        String::elm_type()
+        if posix::enabled() {
+            "Time.Posix".to_string()
+        } else {
+            String::elm_type()
+        }
     }
 
     fn elm_definition() -> Option<String> {
         String::elm_definition()
     }
 }
+// serialized as ( seconds, nanoseconds ), chrono::TimeDelta is the same type
+#[cfg(feature = "chrono")]
+impl_time_tuple!(chrono::Duration, "TimeDelta", "seconds", "nanoseconds");
+
+// Weekday and Month are serialized with the names of their variants
+#[cfg(feature = "chrono")]
+macro_rules! impl_string_enum {
+    ($rust_type: ty, $elm_type: expr, $($variant: expr),+) => {
+        impl Elm for $rust_type {
+            fn elm_type() -> String {
+                $elm_type.to_string()
+            }
+
+            fn elm_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+type {}
+    = {}
+",
+                    $elm_type,
+                    [$($variant),+].join("\n    | ")
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Weekday,
+    "Weekday",
+    "Mon",
+    "Tue",
+    "Wed",
+    "Thu",
+    "Fri",
+    "Sat",
+    "Sun"
+);
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Month,
+    "Month",
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December"
+);
+
+// without the serde-human-readable feature, time types are serialized as tuples
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(time::Date, "Date", "year", "ordinal");
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(time::Duration, "TimeDuration", "seconds", "nanoseconds");
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::OffsetDateTime,
+    "OffsetDateTime",
+    "year",
+    "ordinal",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond",
+    "offsetHours",
+    "offsetMinutes",
+    "offsetSeconds"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::PrimitiveDateTime,
+    "PrimitiveDateTime",
+    "year",
+    "ordinal",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(time::Time, "Time", "hour", "minute", "second", "nanosecond");
+
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Date, "String");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Duration, "String");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::OffsetDateTime, "String");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::PrimitiveDateTime, "String");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Time, "String");
+
+// decimals and big integers are wrapped strings in Elm so that they never go through Float
+#[cfg(any(
+    feature = "rust_decimal",
+    feature = "bigdecimal",
+    feature = "num-bigint"
+))]
+macro_rules! impl_string_backed {
+    ($rust_type: ty, $elm_type: expr) => {
+        impl Elm for $rust_type {
+            fn elm_type() -> String {
+                $elm_type.to_string()
+            }
+
+            fn elm_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+type {elm_type}
+    = {elm_type} String
+",
+                    elm_type = $elm_type
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_string_backed!(rust_decimal::Decimal, "Decimal");
+#[cfg(feature = "bigdecimal")]
+impl_string_backed!(bigdecimal::BigDecimal, "BigDecimal");
+#[cfg(feature = "num-bigint")]
+impl_string_backed!(num_bigint::BigInt, "BigInt");
+#[cfg(feature = "num-bigint")]
+impl_string_backed!(num_bigint::BigUint, "BigUint");
@@ -1,6 +1,6 @@
 //! Contains the `ElmDecode` trait.
 
-use crate::Elm;
+use crate::{posix, settings::large_integer, Elm, ElmMapKey};
 #[cfg(feature = "derive")]
 pub use elm_rs_derive::ElmDecode;
 
@@ -73,6 +73,134 @@ where
     }
 }
 
+macro_rules! impl_tuple {
+    ($elm_name: expr, $decoder_name: expr, $($t: ident: $type_variable: expr),+) => {
+        impl<$($t: Elm + ElmDecode),+> ElmDecode for ($($t,)+) {
+            fn decoder_type() -> String {
+                ::std::format!(
+                    "{} {}",
+                    $decoder_name,
+                    [$(::std::format!("({})", $t::decoder_type())),+].join(" ")
+                )
+            }
+
+            fn decoder_definition() -> Option<String> {
+                let type_variables = [$($type_variable),+];
+                let indices = 0..type_variables.len();
+                Some(::std::format!(
+                    "\
+{decoder_name} : {decoder_types}Json.Decode.Decoder ({elm_name} {type_variables})
+{decoder_name} {decoders} =
+    Json.Decode.succeed {elm_name}
+        {values}
+",
+                    decoder_name = $decoder_name,
+                    decoder_types = type_variables
+                        .iter()
+                        .map(|type_variable| ::std::format!("Json.Decode.Decoder {type_variable} -> "))
+                        .collect::<String>(),
+                    elm_name = $elm_name,
+                    type_variables = type_variables.join(" "),
+                    decoders = indices
+                        .clone()
+                        .map(|idx| ::std::format!("decoder{idx}"))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    values = indices
+                        .map(|idx| ::std::format!("|> Json.Decode.andThen (\\x -> Json.Decode.index {idx} decoder{idx} |> Json.Decode.map x)"))
+                        .collect::<Vec<_>>()
+                        .join("\n        "),
+                ))
+            }
+        }
+    };
+}
+
+impl_tuple!("Tuple4", "tuple4Decoder", T0: "a", T1: "b", T2: "c", T3: "d");
+impl_tuple!("Tuple5", "tuple5Decoder", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e");
+impl_tuple!("Tuple6", "tuple6Decoder", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e", T5: "f");
+impl_tuple!(
+    "Tuple7",
+    "tuple7Decoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g"
+);
+impl_tuple!(
+    "Tuple8",
+    "tuple8Decoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h"
+);
+impl_tuple!(
+    "Tuple9",
+    "tuple9Decoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i"
+);
+impl_tuple!(
+    "Tuple10",
+    "tuple10Decoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j"
+);
+impl_tuple!(
+    "Tuple11",
+    "tuple11Decoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j",
+    T10: "k"
+);
+impl_tuple!(
+    "Tuple12",
+    "tuple12Decoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j",
+    T10: "k",
+    T11: "l"
+);
+
 impl<T: Elm + ElmDecode + ToOwned + ?Sized> ElmDecode for std::borrow::Cow<'_, T> {
     fn decoder_type() -> String {
         T::decoder_type()
@@ -83,6 +211,26 @@ impl<T: Elm + ElmDecode + ToOwned + ?Sized> ElmDecode for std::borrow::Cow<'_, T
     }
 }
 
+impl<T: Elm + ElmDecode> ElmDecode for std::num::Wrapping<T> {
+    fn decoder_type() -> String {
+        T::decoder_type()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        T::decoder_definition()
+    }
+}
+
+impl<T: Elm + ElmDecode> ElmDecode for std::cmp::Reverse<T> {
+    fn decoder_type() -> String {
+        T::decoder_type()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        T::decoder_definition()
+    }
+}
+
 impl<T, const U: usize> ElmDecode for [T; U]
 where
     T: Elm + ElmDecode,
@@ -134,12 +282,50 @@ resultDecoder errDecoder okDecoder =
     }
 }
 
+impl<T: Elm + ElmDecode> ElmDecode for std::ops::Bound<T> {
+    fn decoder_type() -> String {
+        ::std::format!("boundDecoder ({})", T::decoder_type())
+    }
+
+    fn decoder_definition() -> Option<String> {
+        Some(
+            r#"boundDecoder : Json.Decode.Decoder a -> Json.Decode.Decoder (Bound a)
+boundDecoder decoder =
+    Json.Decode.oneOf
+        [ Json.Decode.map Included (Json.Decode.field "Included" decoder)
+        , Json.Decode.map Excluded (Json.Decode.field "Excluded" decoder)
+        , Json.Decode.string |> Json.Decode.andThen (\x -> if x == "Unbounded" then Json.Decode.succeed Unbounded else Json.Decode.fail ("Unexpected variant " ++ x))
+        ]
+"#
+            .to_string(),
+        )
+    }
+}
+
+// serialized as a unit, skipped in derived records
+impl<T: ?Sized> ElmDecode for std::marker::PhantomData<T> {
+    fn decoder_type() -> String {
+        <()>::decoder_type()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        <()>::decoder_definition()
+    }
+}
+
 impl ElmDecode for std::time::SystemTime {
     fn decoder_type() -> String {
-        "systemTimeDecoder".to_string()
+        if posix::enabled() {
+            posix::SYSTEM_TIME_DECODER.to_string()
+        } else {
+            "systemTimeDecoder".to_string()
+        }
     }
 
     fn decoder_definition() -> Option<String> {
+        if posix::enabled() {
+            return None;
+        }
         Some(
             r#"systemTimeDecoder : Json.Decode.Decoder SystemTime
 systemTimeDecoder =
@@ -152,6 +338,21 @@ systemTimeDecoder =
     }
 }
 
+// the representation depends on Settings::large_integers
+macro_rules! impl_large_integer {
+    ($rust_type: ty) => {
+        impl ElmDecode for $rust_type {
+            fn decoder_type() -> String {
+                large_integer::decoder_type(stringify!($rust_type))
+            }
+
+            fn decoder_definition() -> Option<String> {
+                None
+            }
+        }
+    };
+}
+
 macro_rules! impl_builtin {
     ($rust_type: ty, $elm_type: expr, $elm_decoder: expr) => {
         impl ElmDecode for $rust_type {
@@ -182,9 +383,26 @@ macro_rules! impl_builtin_container {
 
 macro_rules! impl_builtin_map {
     ($rust_type: ty) => {
-        impl<T: Elm + ElmDecode> ElmDecode for $rust_type {
+        impl_builtin_map!($rust_type, K::comparable());
+    };
+    // $dict decides whether the map is represented as a Dict
+    ($rust_type: ty, $dict: expr) => {
+        impl<K: ElmMapKey, T: Elm + ElmDecode> ElmDecode for $rust_type {
             fn decoder_type() -> String {
-                ::std::format!("Json.Decode.dict ({})", T::decoder_type())
+                if $dict && K::string_key() {
+                    return ::std::format!("Json.Decode.dict ({})", T::decoder_type());
+                }
+                // every key is parsed and the decoder fails if any of them are invalid
+                ::std::format!(
+                    r#"(Json.Decode.keyValuePairs ({}) |> Json.Decode.andThen (\elmRsPairs -> List.foldr (\( elmRsMapKey, elmRsMapValue ) elmRsMapAcc -> Maybe.map2 (\elmRsMapParsed elmRsMapRest -> ( elmRsMapParsed, elmRsMapValue ) :: elmRsMapRest) (({}) elmRsMapKey) elmRsMapAcc) (Just []) elmRsPairs |> Maybe.map (Json.Decode.succeed << {}) |> Maybe.withDefault (Json.Decode.fail "Invalid map key")))"#,
+                    T::decoder_type(),
+                    K::key_decoder(),
+                    if $dict {
+                        "Dict.fromList"
+                    } else {
+                        "identity"
+                    }
+                )
             }
 
             fn decoder_definition() -> Option<String> {
@@ -194,6 +412,29 @@ macro_rules! impl_builtin_map {
     };
 }
 
+macro_rules! impl_builtin_range {
+    ($rust_type: ty, $elm_name: expr, $elm_decoder: expr) => {
+        impl<T: Elm + ElmDecode> ElmDecode for $rust_type {
+            fn decoder_type() -> String {
+                ::std::format!("{} ({})", $elm_decoder, T::decoder_type())
+            }
+
+            fn decoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    r#"{decoder} : Json.Decode.Decoder a -> Json.Decode.Decoder ({elm_name} a)
+{decoder} decoder =
+    Json.Decode.succeed {elm_name}
+    |> Json.Decode.andThen (\x -> Json.Decode.map x (Json.Decode.field "start" decoder))
+    |> Json.Decode.andThen (\x -> Json.Decode.map x (Json.Decode.field "end" decoder))
+"#,
+                    decoder = $elm_decoder,
+                    elm_name = $elm_name,
+                ))
+            }
+        }
+    };
+}
+
 macro_rules! impl_builtin_ptr {
     ($rust_type: ty) => {
         impl<T: Elm + ElmDecode + ?Sized> ElmDecode for $rust_type {
@@ -215,57 +456,74 @@ impl_builtin!(std::sync::atomic::AtomicBool, "Bool", "Json.Decode.bool");
 impl_builtin!(std::sync::atomic::AtomicU8, "Int", "Json.Decode.int");
 impl_builtin!(std::sync::atomic::AtomicU16, "Int", "Json.Decode.int");
 impl_builtin!(std::sync::atomic::AtomicU32, "Int", "Json.Decode.int");
-impl_builtin!(std::sync::atomic::AtomicU64, "Int", "Json.Decode.int");
+impl_large_integer!(std::sync::atomic::AtomicU64);
 impl_builtin!(std::sync::atomic::AtomicUsize, "Int", "Json.Decode.int");
 impl_builtin!(std::sync::atomic::AtomicI8, "Int", "Json.Decode.int");
 impl_builtin!(std::sync::atomic::AtomicI16, "Int", "Json.Decode.int");
 impl_builtin!(std::sync::atomic::AtomicI32, "Int", "Json.Decode.int");
-impl_builtin!(std::sync::atomic::AtomicI64, "Int", "Json.Decode.int");
+impl_large_integer!(std::sync::atomic::AtomicI64);
 impl_builtin!(std::sync::atomic::AtomicIsize, "Int", "Json.Decode.int");
-impl_builtin_map!(std::collections::BTreeMap<String,T>);
+impl_builtin_map!(std::collections::BTreeMap<K, T>);
 impl_builtin_container!(std::collections::BTreeSet<T>, "List", "Json.Decode.list");
+impl_builtin_container!(std::collections::BinaryHeap<T>, "List", "Json.Decode.list");
 impl_builtin_ptr!(Box<T>);
 impl_builtin_ptr!(std::cell::Cell<T>);
-impl_builtin_map!(std::collections::HashMap<String,T>);
+impl_builtin_map!(std::collections::HashMap<K, T>);
 impl_builtin_container!(std::collections::HashSet<T>, "List", "Json.Decode.list");
-// todo ipaddrs
+impl_builtin!(std::net::IpAddr, "String", "Json.Decode.string");
+impl_builtin!(std::net::Ipv4Addr, "String", "Json.Decode.string");
+impl_builtin!(std::net::Ipv6Addr, "String", "Json.Decode.string");
 impl_builtin_container!(std::collections::LinkedList<T>, "List", "Json.Decode.list");
 impl_builtin_ptr!(std::sync::Mutex<T>);
 impl_builtin!(std::num::NonZeroU8, "Int", "Json.Decode.int");
 impl_builtin!(std::num::NonZeroU16, "Int", "Json.Decode.int");
 impl_builtin!(std::num::NonZeroU32, "Int", "Json.Decode.int");
-impl_builtin!(std::num::NonZeroU64, "Int", "Json.Decode.int");
-impl_builtin!(std::num::NonZeroU128, "Int", "Json.Decode.int");
+impl_large_integer!(std::num::NonZeroU64);
+impl_large_integer!(std::num::NonZeroU128);
 impl_builtin!(std::num::NonZeroUsize, "Int", "Json.Decode.int");
 impl_builtin!(std::num::NonZeroI8, "Int", "Json.Decode.int");
 impl_builtin!(std::num::NonZeroI16, "Int", "Json.Decode.int");
 impl_builtin!(std::num::NonZeroI32, "Int", "Json.Decode.int");
-impl_builtin!(std::num::NonZeroI64, "Int", "Json.Decode.int");
-impl_builtin!(std::num::NonZeroI128, "Int", "Json.Decode.int");
+impl_large_integer!(std::num::NonZeroI64);
+impl_large_integer!(std::num::NonZeroI128);
 impl_builtin!(std::num::NonZeroIsize, "Int", "Json.Decode.int");
 impl_builtin_container!(Option<T>, "Maybe", "Json.Decode.nullable");
 impl_builtin!(std::path::Path, "String", "Json.Decode.string");
 impl_builtin!(std::path::PathBuf, "String", "Json.Decode.string");
-// todo phantomdata
+impl_builtin_range!(std::ops::Range<T>, "Range", "rangeDecoder");
+impl_builtin_range!(
+    std::ops::RangeInclusive<T>,
+    "RangeInclusive",
+    "rangeInclusiveDecoder"
+);
 impl_builtin_ptr!(std::rc::Rc<T>);
 impl_builtin_ptr!(std::cell::RefCell<T>);
 impl_builtin_ptr!(std::sync::RwLock<T>);
-// todo socketaddrs
+impl_builtin!(std::net::SocketAddr, "String", "Json.Decode.string");
+impl_builtin!(std::net::SocketAddrV4, "String", "Json.Decode.string");
+impl_builtin!(std::net::SocketAddrV6, "String", "Json.Decode.string");
 impl_builtin!(String, "String", "Json.Decode.string");
 impl_builtin_container!(Vec<T>, "List", "Json.Decode.list");
+impl_builtin_container!(std::collections::VecDeque<T>, "List", "Json.Decode.list");
 impl_builtin_container!([T], "List", "Json.Decode.list");
 impl_builtin!(bool, "Bool", "Json.Decode.bool");
+// serialized as a string that must contain exactly one character
+impl_builtin!(
+    char,
+    "Char",
+    r#"(Json.Decode.string |> Json.Decode.andThen (\elmRsString -> String.uncons elmRsString |> Maybe.andThen (\( elmRsChar, elmRsRest ) -> if String.isEmpty elmRsRest then Just elmRsChar else Nothing) |> Maybe.map Json.Decode.succeed |> Maybe.withDefault (Json.Decode.fail ("Expected a single character, found " ++ elmRsString))))"#
+);
 impl_builtin!(u8, "Int", "Json.Decode.int");
 impl_builtin!(u16, "Int", "Json.Decode.int");
 impl_builtin!(u32, "Int", "Json.Decode.int");
-impl_builtin!(u64, "Int", "Json.Decode.int");
-impl_builtin!(u128, "Int", "Json.Decode.int");
+impl_large_integer!(u64);
+impl_large_integer!(u128);
 impl_builtin!(usize, "Int", "Json.Decode.int");
 impl_builtin!(i8, "Int", "Json.Decode.int");
 impl_builtin!(i16, "Int", "Json.Decode.int");
 impl_builtin!(i32, "Int", "Json.Decode.int");
-impl_builtin!(i64, "Int", "Json.Decode.int");
-impl_builtin!(i128, "Int", "Json.Decode.int");
+impl_large_integer!(i64);
+impl_large_integer!(i128);
 impl_builtin!(isize, "Int", "Json.Decode.int");
 impl_builtin!(f32, "Float", "Json.Decode.float");
 impl_builtin!(f64, "Float", "Json.Decode.float");
@@ -274,19 +532,422 @@ impl_builtin!(str, "String", "Json.Decode.string");
 #[cfg(feature = "uuid")]
 impl_builtin!(uuid::Uuid, "String", "Json.Decode.string");
 
+#[cfg(feature = "url")]
+impl_builtin!(url::Url, "String", "Json.Decode.string");
+#[cfg(feature = "semver")]
+impl_builtin!(semver::Version, "String", "Json.Decode.string");
+#[cfg(feature = "semver")]
+impl_builtin!(semver::VersionReq, "String", "Json.Decode.string");
+// mime and http don't implement serde, so their representations follow Display and the http-serde crate
+#[cfg(feature = "mime")]
+impl_builtin!(mime::Mime, "String", "Json.Decode.string");
+#[cfg(feature = "http")]
+impl_builtin!(http::Method, "String", "Json.Decode.string");
+#[cfg(feature = "http")]
+impl_builtin!(http::Uri, "String", "Json.Decode.string");
+#[cfg(feature = "http")]
+impl_builtin!(http::StatusCode, "Int", "Json.Decode.int");
+
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::Value, "Json.Encode.Value", "Json.Decode.value");
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::Map<String, serde_json::Value>, "Json.Encode.Value", "Json.Decode.value");
+#[cfg(feature = "serde_json")]
+impl_builtin!(
+    serde_json::value::RawValue,
+    "Json.Encode.Value",
+    "Json.Decode.value"
+);
+
+#[cfg(feature = "indexmap")]
+impl_builtin_map!(
+    indexmap::IndexMap<K, T>,
+    K::comparable() && !crate::Settings::current().ordered_index_maps
+);
+#[cfg(feature = "indexmap")]
+impl_builtin_container!(indexmap::IndexSet<T>, "List", "Json.Decode.list");
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> ElmDecode for smallvec::SmallVec<A>
+where
+    A::Item: Elm + ElmDecode,
+{
+    fn decoder_type() -> String {
+        Vec::<A::Item>::decoder_type()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        Vec::<A::Item>::decoder_definition()
+    }
+}
+#[cfg(feature = "arrayvec")]
+impl<T: Elm + ElmDecode, const CAP: usize> ElmDecode for arrayvec::ArrayVec<T, CAP> {
+    fn decoder_type() -> String {
+        Vec::<T>::decoder_type()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        Vec::<T>::decoder_definition()
+    }
+}
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> ElmDecode for arrayvec::ArrayString<CAP> {
+    fn decoder_type() -> String {
+        String::decoder_type()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        String::decoder_definition()
+    }
+}
+// serde_json serializes bytes as arrays of integers
+#[cfg(feature = "bytes")]
+impl_builtin!(
+    bytes::Bytes,
+    "List (Int)",
+    "Json.Decode.list (Json.Decode.int)"
+);
+#[cfg(feature = "bytes")]
+impl_builtin!(
+    bytes::BytesMut,
+    "List (Int)",
+    "Json.Decode.list (Json.Decode.int)"
+);
+#[cfg(feature = "serde_bytes")]
+impl_builtin!(
+    serde_bytes::ByteBuf,
+    "List (Int)",
+    "Json.Decode.list (Json.Decode.int)"
+);
+#[cfg(feature = "serde_bytes")]
+impl_builtin!(
+    serde_bytes::Bytes,
+    "List (Int)",
+    "Json.Decode.list (Json.Decode.int)"
+);
+
+#[cfg(any(
+    all(feature = "time", not(feature = "time-serde-human-readable")),
+    feature = "chrono"
+))]
+macro_rules! impl_time_tuple {
+    ($rust_type: ty, $elm_type: expr, $elm_decoder: expr, $($field: expr),+) => {
+        impl ElmDecode for $rust_type {
+            fn decoder_type() -> String {
+                $elm_decoder.to_string()
+            }
+
+            fn decoder_definition() -> Option<String> {
+                let fields: &[&str] = &[$($field),+];
+                Some(::std::format!(
+                    "\
+{decoder} : Json.Decode.Decoder {elm_type}
+{decoder} =
+    Json.Decode.succeed {elm_type}
+    {indices}
+",
+                    decoder = $elm_decoder,
+                    elm_type = $elm_type,
+                    indices = (0..fields.len())
+                        .map(|idx| ::std::format!("|> Json.Decode.andThen (\\x -> Json.Decode.map x (Json.Decode.index {} (Json.Decode.int)))", idx))
+                        .collect::<Vec<_>>()
+                        .join("\n    ")
+                ))
+            }
+        }
+    };
+}
+
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveTime, "String", "Json.Decode.string");
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveDate, "String", "Json.Decode.string");
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveDateTime, "String", "Json.Decode.string");
+// FixedOffset doesn't implement serde, so its representation follows Display
+#[cfg(feature = "chrono")]
+impl_builtin!(chrono::FixedOffset, "String", "Json.Decode.string");
 #[cfg(feature = "chrono")]
 impl<T: chrono::TimeZone> ElmDecode for chrono::DateTime<T> {
     fn decoder_type() -> String {
-        String::decoder_type()
+        if posix::enabled() {
+            posix::RFC3339_DECODER.to_string()
+        } else {
+            String::decoder_type()
+        }
     }
 
     fn decoder_definition() -> Option<String> {
         String::decoder_definition()
     }
 }
+// serialized as ( seconds, nanoseconds ), chrono::TimeDelta is the same type
+#[cfg(feature = "chrono")]
+impl_time_tuple!(
+    chrono::Duration,
+    "TimeDelta",
+    "timeDeltaDecoder",
+    "seconds",
+    "nanoseconds"
+);
+
+// Weekday and Month are serialized with the names of their variants
+#[cfg(feature = "chrono")]
+macro_rules! impl_string_enum {
+    ($rust_type: ty, $elm_type: expr, $elm_decoder: expr, $($variant: expr),+) => {
+        impl ElmDecode for $rust_type {
+            fn decoder_type() -> String {
+                $elm_decoder.to_string()
+            }
+
+            fn decoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+{decoder} : Json.Decode.Decoder {elm_type}
+{decoder} =
+    Json.Decode.string
+        |> Json.Decode.andThen
+            (\\elmRsValue ->
+                case elmRsValue of
+                    {cases}
+                    unexpected ->
+                        Json.Decode.fail <| \"Unexpected variant \" ++ unexpected
+            )
+",
+                    decoder = $elm_decoder,
+                    elm_type = $elm_type,
+                    cases = [$(::std::format!("\"{0}\" ->\n                        Json.Decode.succeed {0}", $variant)),+].join("\n                    ")
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Weekday,
+    "Weekday",
+    "weekdayDecoder",
+    "Mon",
+    "Tue",
+    "Wed",
+    "Thu",
+    "Fri",
+    "Sat",
+    "Sun"
+);
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Month,
+    "Month",
+    "monthDecoder",
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December"
+);
+
+// without the serde-human-readable feature, time types are serialized as tuples
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(time::Date, "Date", "dateDecoder", "year", "ordinal");
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::Duration,
+    "TimeDuration",
+    "timeDurationDecoder",
+    "seconds",
+    "nanoseconds"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::OffsetDateTime,
+    "OffsetDateTime",
+    "offsetDateTimeDecoder",
+    "year",
+    "ordinal",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond",
+    "offsetHours",
+    "offsetMinutes",
+    "offsetSeconds"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::PrimitiveDateTime,
+    "PrimitiveDateTime",
+    "primitiveDateTimeDecoder",
+    "year",
+    "ordinal",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::Time,
+    "Time",
+    "timeDecoder",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond"
+);
+
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Date, "String", "Json.Decode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Duration, "String", "Json.Decode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::OffsetDateTime, "String", "Json.Decode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::PrimitiveDateTime, "String", "Json.Decode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Time, "String", "Json.Decode.string");
+
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+macro_rules! impl_string_backed {
+    ($rust_type: ty, $elm_type: expr, $elm_decoder: expr, $value_decoder: expr) => {
+        impl ElmDecode for $rust_type {
+            fn decoder_type() -> String {
+                $elm_decoder.to_string()
+            }
+
+            fn decoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+{elm_decoder} : Json.Decode.Decoder {elm_type}
+{elm_decoder} =
+    Json.Decode.map {elm_type} {value_decoder}
+",
+                    elm_decoder = $elm_decoder,
+                    elm_type = $elm_type,
+                    value_decoder = $value_decoder,
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "rust_decimal", not(feature = "rust_decimal-serde-float")))]
+impl_string_backed!(
+    rust_decimal::Decimal,
+    "Decimal",
+    "decimalDecoder",
+    "Json.Decode.string"
+);
+// with serde-float, rust_decimal serializes the value as a number
+#[cfg(feature = "rust_decimal-serde-float")]
+impl_string_backed!(
+    rust_decimal::Decimal,
+    "Decimal",
+    "decimalDecoder",
+    "(Json.Decode.map String.fromFloat Json.Decode.float)"
+);
+#[cfg(feature = "bigdecimal")]
+impl_string_backed!(
+    bigdecimal::BigDecimal,
+    "BigDecimal",
+    "bigDecimalDecoder",
+    "Json.Decode.string"
+);
+
+// num-bigint serializes the magnitude as a list of base 2^32 digits, least significant first,
+// which are turned into a decimal string by repeatedly dividing by 10
+#[cfg(feature = "num-bigint")]
+const BIG_UINT_DIGITS: &str = r#"        elmRsDropZeros elmRsDigits =
+            case elmRsDigits of
+                0 :: elmRsRest ->
+                    elmRsDropZeros elmRsRest
+
+                _ ->
+                    elmRsDigits
+
+        elmRsTrim elmRsDigits =
+            List.reverse (elmRsDropZeros (List.reverse elmRsDigits))
+
+        elmRsDivide elmRsDigits =
+            List.foldr
+                (\elmRsDigit ( elmRsQuotient, elmRsRemainder ) ->
+                    let
+                        elmRsValue =
+                            elmRsRemainder * 4294967296 + elmRsDigit
+                    in
+                    ( floor (toFloat elmRsValue / 10) :: elmRsQuotient, modBy 10 elmRsValue )
+                )
+                ( [], 0 )
+                elmRsDigits
+
+        elmRsToString elmRsDigits elmRsString =
+            if List.isEmpty elmRsDigits then
+                if String.isEmpty elmRsString then
+                    "0"
+
+                else
+                    elmRsString
+
+            else
+                let
+                    ( elmRsNextDigits, elmRsNextRemainder ) =
+                        elmRsDivide elmRsDigits
+                in
+                elmRsToString (elmRsTrim elmRsNextDigits) (String.fromInt elmRsNextRemainder ++ elmRsString)"#;
+
+#[cfg(feature = "num-bigint")]
+impl ElmDecode for num_bigint::BigUint {
+    fn decoder_type() -> String {
+        "bigUintDecoder".to_string()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        Some(format!(
+            r#"bigUintDecoder : Json.Decode.Decoder BigUint
+bigUintDecoder =
+    let
+{}
+    in
+    Json.Decode.map (\elmRsMagnitude -> BigUint (elmRsToString (elmRsTrim elmRsMagnitude) "")) (Json.Decode.list Json.Decode.int)
+"#,
+            BIG_UINT_DIGITS
+        ))
+    }
+}
+
+// serialized as ( sign, magnitude ) where the sign is -1, 0 or 1
+#[cfg(feature = "num-bigint")]
+impl ElmDecode for num_bigint::BigInt {
+    fn decoder_type() -> String {
+        "bigIntDecoder".to_string()
+    }
+
+    fn decoder_definition() -> Option<String> {
+        Some(format!(
+            r#"bigIntDecoder : Json.Decode.Decoder BigInt
+bigIntDecoder =
+    let
+{}
+
+        elmRsSigned elmRsSign elmRsMagnitude =
+            if elmRsSign < 0 then
+                "-" ++ elmRsMagnitude
+
+            else
+                elmRsMagnitude
+    in
+    Json.Decode.map2 (\elmRsSign elmRsMagnitude -> BigInt (elmRsSigned elmRsSign (elmRsToString (elmRsTrim elmRsMagnitude) "")))
+        (Json.Decode.index 0 Json.Decode.int)
+        (Json.Decode.index 1 (Json.Decode.list Json.Decode.int))
+"#,
+            BIG_UINT_DIGITS
+        ))
+    }
+}
@@ -1,6 +1,6 @@
 //! Contains the `ElmEncode` trait.
 
-use crate::Elm;
+use crate::{posix, settings::large_integer, Elm, ElmMapKey};
 #[cfg(feature = "derive")]
 pub use elm_rs_derive::ElmEncode;
 
@@ -76,6 +76,135 @@ where
     }
 }
 
+macro_rules! impl_tuple {
+    ($elm_name: expr, $encoder_name: expr, $($t: ident: $type_variable: expr),+) => {
+        impl<$($t: Elm + ElmEncode),+> ElmEncode for ($($t,)+) {
+            fn encoder_type() -> String {
+                ::std::format!(
+                    "{} {}",
+                    $encoder_name,
+                    [$(::std::format!("({})", $t::encoder_type())),+].join(" ")
+                )
+            }
+
+            fn encoder_definition() -> Option<String> {
+                let type_variables = [$($type_variable),+];
+                let indices = 0..type_variables.len();
+                Some(::std::format!(
+                    "\
+{encoder_name} : {encoder_types}{elm_name} {type_variables} -> Json.Encode.Value
+{encoder_name} {encoders} tuple =
+    Json.Encode.list identity
+        [ {values}
+        ]
+",
+                    encoder_name = $encoder_name,
+                    encoder_types = type_variables
+                        .iter()
+                        .map(|type_variable| ::std::format!("({type_variable} -> Json.Encode.Value) -> "))
+                        .collect::<String>(),
+                    elm_name = $elm_name,
+                    type_variables = type_variables.join(" "),
+                    encoders = indices
+                        .clone()
+                        .map(|idx| ::std::format!("encoder{idx}"))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    values = indices
+                        .map(|idx| ::std::format!("encoder{idx} tuple.t{idx}"))
+                        .collect::<Vec<_>>()
+                        .join("\n        , "),
+                ))
+            }
+        }
+    };
+}
+
+impl_tuple!("Tuple4", "tuple4Encoder", T0: "a", T1: "b", T2: "c", T3: "d");
+impl_tuple!("Tuple5", "tuple5Encoder", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e");
+impl_tuple!("Tuple6", "tuple6Encoder", T0: "a", T1: "b", T2: "c", T3: "d", T4: "e", T5: "f");
+impl_tuple!(
+    "Tuple7",
+    "tuple7Encoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g"
+);
+impl_tuple!(
+    "Tuple8",
+    "tuple8Encoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h"
+);
+impl_tuple!(
+    "Tuple9",
+    "tuple9Encoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i"
+);
+impl_tuple!(
+    "Tuple10",
+    "tuple10Encoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j"
+);
+impl_tuple!(
+    "Tuple11",
+    "tuple11Encoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j",
+    T10: "k"
+);
+impl_tuple!(
+    "Tuple12",
+    "tuple12Encoder",
+    T0: "a",
+    T1: "b",
+    T2: "c",
+    T3: "d",
+    T4: "e",
+    T5: "f",
+    T6: "g",
+    T7: "h",
+    T8: "i",
+    T9: "j",
+    T10: "k",
+    T11: "l"
+);
+
 impl<T: Elm + ElmEncode + ToOwned + ?Sized> ElmEncode for std::borrow::Cow<'_, T> {
     fn encoder_type() -> String {
         T::encoder_type()
@@ -86,6 +215,26 @@ impl<T: Elm + ElmEncode + ToOwned + ?Sized> ElmEncode for std::borrow::Cow<'_, T
     }
 }
 
+impl<T: Elm + ElmEncode> ElmEncode for std::num::Wrapping<T> {
+    fn encoder_type() -> String {
+        T::encoder_type()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        T::encoder_definition()
+    }
+}
+
+impl<T: Elm + ElmEncode> ElmEncode for std::cmp::Reverse<T> {
+    fn encoder_type() -> String {
+        T::encoder_type()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        T::encoder_definition()
+    }
+}
+
 impl<T, const U: usize> ElmEncode for [T; U]
 where
     T: Elm + ElmEncode,
@@ -139,12 +288,52 @@ resultEncoder errEncoder okEncoder enum =
     }
 }
 
+impl<T: Elm + ElmEncode> ElmEncode for std::ops::Bound<T> {
+    fn encoder_type() -> String {
+        ::std::format!("boundEncoder ({})", T::encoder_type())
+    }
+
+    fn encoder_definition() -> Option<String> {
+        Some(
+            r#"boundEncoder : (a -> Json.Encode.Value) -> Bound a -> Json.Encode.Value
+boundEncoder encoder enum =
+    case enum of
+        Included inner ->
+            Json.Encode.object [ ( "Included", encoder inner ) ]
+        Excluded inner ->
+            Json.Encode.object [ ( "Excluded", encoder inner ) ]
+        Unbounded ->
+            Json.Encode.string "Unbounded"
+"#
+            .to_string(),
+        )
+    }
+}
+
+// serialized as a unit, skipped in derived records
+impl<T: ?Sized> ElmEncode for std::marker::PhantomData<T> {
+    fn encoder_type() -> String {
+        <()>::encoder_type()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        <()>::encoder_definition()
+    }
+}
+
 impl ElmEncode for std::time::SystemTime {
     fn encoder_type() -> String {
-        "systemTimeEncoder".to_string()
+        if posix::enabled() {
+            posix::SYSTEM_TIME_ENCODER.to_string()
+        } else {
+            "systemTimeEncoder".to_string()
+        }
     }
 
     fn encoder_definition() -> Option<String> {
+        if posix::enabled() {
+            return None;
+        }
         Some(
             r#"systemTimeEncoder : SystemTime -> Json.Encode.Value
 systemTimeEncoder duration =
@@ -158,6 +347,21 @@ systemTimeEncoder duration =
     }
 }
 
+// the representation depends on Settings::large_integers
+macro_rules! impl_large_integer {
+    ($rust_type: ty) => {
+        impl ElmEncode for $rust_type {
+            fn encoder_type() -> String {
+                large_integer::encoder_type(stringify!($rust_type))
+            }
+
+            fn encoder_definition() -> Option<String> {
+                None
+            }
+        }
+    };
+}
+
 macro_rules! impl_builtin {
     ($rust_type: ty, $elm_type: expr, $elm_encoder: expr) => {
         impl ElmEncode for $rust_type {
@@ -188,9 +392,25 @@ macro_rules! impl_builtin_container {
 
 macro_rules! impl_builtin_map {
     ($rust_type: ty) => {
-        impl<T: Elm + ElmEncode> ElmEncode for $rust_type {
+        impl_builtin_map!($rust_type, K::comparable());
+    };
+    // $dict decides whether the map is represented as a Dict
+    ($rust_type: ty, $dict: expr) => {
+        impl<K: ElmMapKey, T: Elm + ElmEncode> ElmEncode for $rust_type {
             fn encoder_type() -> String {
-                ::std::format!("Json.Encode.dict identity ({})", T::encoder_type())
+                if $dict {
+                    ::std::format!(
+                        "Json.Encode.dict ({}) ({})",
+                        K::key_encoder(),
+                        T::encoder_type()
+                    )
+                } else {
+                    ::std::format!(
+                        "(Json.Encode.object << List.map (Tuple.mapBoth ({}) ({})))",
+                        K::key_encoder(),
+                        T::encoder_type()
+                    )
+                }
             }
 
             fn encoder_definition() -> Option<String> {
@@ -200,6 +420,30 @@ macro_rules! impl_builtin_map {
     };
 }
 
+macro_rules! impl_builtin_range {
+    ($rust_type: ty, $elm_name: expr, $elm_encoder: expr) => {
+        impl<T: Elm + ElmEncode> ElmEncode for $rust_type {
+            fn encoder_type() -> String {
+                ::std::format!("{} ({})", $elm_encoder, T::encoder_type())
+            }
+
+            fn encoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    r#"{encoder} : (a -> Json.Encode.Value) -> {elm_name} a -> Json.Encode.Value
+{encoder} encoder range =
+    Json.Encode.object
+    [ ( "start", encoder range.start )
+    , ( "end", encoder range.end )
+    ]
+"#,
+                    encoder = $elm_encoder,
+                    elm_name = $elm_name,
+                ))
+            }
+        }
+    };
+}
+
 macro_rules! impl_builtin_ptr {
     ($rust_type: ty) => {
         impl<T: Elm + ElmEncode + ?Sized> ElmEncode for $rust_type {
@@ -221,33 +465,36 @@ impl_builtin!(std::sync::atomic::AtomicBool, "Bool", "Json.Encode.bool");
 impl_builtin!(std::sync::atomic::AtomicU8, "Int", "Json.Encode.int");
 impl_builtin!(std::sync::atomic::AtomicU16, "Int", "Json.Encode.int");
 impl_builtin!(std::sync::atomic::AtomicU32, "Int", "Json.Encode.int");
-impl_builtin!(std::sync::atomic::AtomicU64, "Int", "Json.Encode.int");
+impl_large_integer!(std::sync::atomic::AtomicU64);
 impl_builtin!(std::sync::atomic::AtomicUsize, "Int", "Json.Encode.int");
 impl_builtin!(std::sync::atomic::AtomicI8, "Int", "Json.Encode.int");
 impl_builtin!(std::sync::atomic::AtomicI16, "Int", "Json.Encode.int");
 impl_builtin!(std::sync::atomic::AtomicI32, "Int", "Json.Encode.int");
-impl_builtin!(std::sync::atomic::AtomicI64, "Int", "Json.Encode.int");
+impl_large_integer!(std::sync::atomic::AtomicI64);
 impl_builtin!(std::sync::atomic::AtomicIsize, "Int", "Json.Encode.int");
-impl_builtin_map!(std::collections::BTreeMap<String,T>);
+impl_builtin_map!(std::collections::BTreeMap<K, T>);
 impl_builtin_container!(std::collections::BTreeSet<T>, "List", "Json.Encode.list");
+impl_builtin_container!(std::collections::BinaryHeap<T>, "List", "Json.Encode.list");
 impl_builtin_ptr!(Box<T>);
 impl_builtin_ptr!(std::cell::Cell<T>);
-impl_builtin_map!(std::collections::HashMap<String,T>);
+impl_builtin_map!(std::collections::HashMap<K, T>);
 impl_builtin_container!(std::collections::HashSet<T>, "List", "Json.Encode.list");
-// todo ipaddrs
+impl_builtin!(std::net::IpAddr, "String", "Json.Encode.string");
+impl_builtin!(std::net::Ipv4Addr, "String", "Json.Encode.string");
+impl_builtin!(std::net::Ipv6Addr, "String", "Json.Encode.string");
 impl_builtin_container!(std::collections::LinkedList<T>, "List", "Json.Encode.list");
 impl_builtin_ptr!(std::sync::Mutex<T>);
 impl_builtin!(std::num::NonZeroU8, "Int", "Json.Encode.int");
 impl_builtin!(std::num::NonZeroU16, "Int", "Json.Encode.int");
 impl_builtin!(std::num::NonZeroU32, "Int", "Json.Encode.int");
-impl_builtin!(std::num::NonZeroU64, "Int", "Json.Encode.int");
-impl_builtin!(std::num::NonZeroU128, "Int", "Json.Encode.int");
+impl_large_integer!(std::num::NonZeroU64);
+impl_large_integer!(std::num::NonZeroU128);
 impl_builtin!(std::num::NonZeroUsize, "Int", "Json.Encode.int");
 impl_builtin!(std::num::NonZeroI8, "Int", "Json.Encode.int");
 impl_builtin!(std::num::NonZeroI16, "Int", "Json.Encode.int");
 impl_builtin!(std::num::NonZeroI32, "Int", "Json.Encode.int");
-impl_builtin!(std::num::NonZeroI64, "Int", "Json.Encode.int");
-impl_builtin!(std::num::NonZeroI128, "Int", "Json.Encode.int");
+impl_large_integer!(std::num::NonZeroI64);
+impl_large_integer!(std::num::NonZeroI128);
 impl_builtin!(std::num::NonZeroIsize, "Int", "Json.Encode.int");
 impl_builtin_container!(
     Option<T>,
@@ -256,26 +503,35 @@ impl_builtin_container!(
 );
 impl_builtin!(std::path::Path, "String", "Json.Encode.string");
 impl_builtin!(std::path::PathBuf, "String", "Json.Encode.string");
-// todo phantomdata
+impl_builtin_range!(std::ops::Range<T>, "Range", "rangeEncoder");
+impl_builtin_range!(
+    std::ops::RangeInclusive<T>,
+    "RangeInclusive",
+    "rangeInclusiveEncoder"
+);
 impl_builtin_ptr!(std::rc::Rc<T>);
 impl_builtin_ptr!(std::cell::RefCell<T>);
 impl_builtin_ptr!(std::sync::RwLock<T>);
-// todo socketaddrs
+impl_builtin!(std::net::SocketAddr, "String", "Json.Encode.string");
+impl_builtin!(std::net::SocketAddrV4, "String", "Json.Encode.string");
+impl_builtin!(std::net::SocketAddrV6, "String", "Json.Encode.string");
 impl_builtin!(String, "String", "Json.Encode.string");
 impl_builtin_container!(Vec<T>, "List", "Json.Encode.list");
+impl_builtin_container!(std::collections::VecDeque<T>, "List", "Json.Encode.list");
 impl_builtin_container!([T], "List", "Json.Encode.list");
 impl_builtin!(bool, "Bool", "Json.Encode.bool");
+impl_builtin!(char, "Char", "(Json.Encode.string << String.fromChar)");
 impl_builtin!(u8, "Int", "Json.Encode.int");
 impl_builtin!(u16, "Int", "Json.Encode.int");
 impl_builtin!(u32, "Int", "Json.Encode.int");
-impl_builtin!(u64, "Int", "Json.Encode.int");
-impl_builtin!(u128, "Int", "Json.Encode.int");
+impl_large_integer!(u64);
+impl_large_integer!(u128);
 impl_builtin!(usize, "Int", "Json.Encode.int");
 impl_builtin!(i8, "Int", "Json.Encode.int");
 impl_builtin!(i16, "Int", "Json.Encode.int");
 impl_builtin!(i32, "Int", "Json.Encode.int");
-impl_builtin!(i64, "Int", "Json.Encode.int");
-impl_builtin!(i128, "Int", "Json.Encode.int");
+impl_large_integer!(i64);
+impl_large_integer!(i128);
 impl_builtin!(isize, "Int", "Json.Encode.int");
 impl_builtin!(f32, "Float", "Json.Encode.float");
 impl_builtin!(f64, "Float", "Json.Encode.float");
@@ -284,19 +540,395 @@ impl_builtin!(str, "String", "Json.Encode.string");
 #[cfg(feature = "uuid")]
 impl_builtin!(uuid::Uuid, "String", "Json.Encode.string");
 
+#[cfg(feature = "url")]
+impl_builtin!(url::Url, "String", "Json.Encode.string");
+#[cfg(feature = "semver")]
+impl_builtin!(semver::Version, "String", "Json.Encode.string");
+#[cfg(feature = "semver")]
+impl_builtin!(semver::VersionReq, "String", "Json.Encode.string");
+// mime and http don't implement serde, so their representations follow Display and the http-serde crate
+#[cfg(feature = "mime")]
+impl_builtin!(mime::Mime, "String", "Json.Encode.string");
+#[cfg(feature = "http")]
+impl_builtin!(http::Method, "String", "Json.Encode.string");
+#[cfg(feature = "http")]
+impl_builtin!(http::Uri, "String", "Json.Encode.string");
+#[cfg(feature = "http")]
+impl_builtin!(http::StatusCode, "Int", "Json.Encode.int");
+
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::Value, "Json.Encode.Value", "identity");
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::Map<String, serde_json::Value>, "Json.Encode.Value", "identity");
+#[cfg(feature = "serde_json")]
+impl_builtin!(serde_json::value::RawValue, "Json.Encode.Value", "identity");
+
+#[cfg(feature = "indexmap")]
+impl_builtin_map!(
+    indexmap::IndexMap<K, T>,
+    K::comparable() && !crate::Settings::current().ordered_index_maps
+);
+#[cfg(feature = "indexmap")]
+impl_builtin_container!(indexmap::IndexSet<T>, "List", "Json.Encode.list");
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> ElmEncode for smallvec::SmallVec<A>
+where
+    A::Item: Elm + ElmEncode,
+{
+    fn encoder_type() -> String {
+        Vec::<A::Item>::encoder_type()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        Vec::<A::Item>::encoder_definition()
+    }
+}
+#[cfg(feature = "arrayvec")]
+impl<T: Elm + ElmEncode, const CAP: usize> ElmEncode for arrayvec::ArrayVec<T, CAP> {
+    fn encoder_type() -> String {
+        Vec::<T>::encoder_type()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        Vec::<T>::encoder_definition()
+    }
+}
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> ElmEncode for arrayvec::ArrayString<CAP> {
+    fn encoder_type() -> String {
+        String::encoder_type()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        String::encoder_definition()
+    }
+}
+// serde_json serializes bytes as arrays of integers
+#[cfg(feature = "bytes")]
+impl_builtin!(
+    bytes::Bytes,
+    "List (Int)",
+    "Json.Encode.list (Json.Encode.int)"
+);
+#[cfg(feature = "bytes")]
+impl_builtin!(
+    bytes::BytesMut,
+    "List (Int)",
+    "Json.Encode.list (Json.Encode.int)"
+);
+#[cfg(feature = "serde_bytes")]
+impl_builtin!(
+    serde_bytes::ByteBuf,
+    "List (Int)",
+    "Json.Encode.list (Json.Encode.int)"
+);
+#[cfg(feature = "serde_bytes")]
+impl_builtin!(
+    serde_bytes::Bytes,
+    "List (Int)",
+    "Json.Encode.list (Json.Encode.int)"
+);
+
+#[cfg(any(
+    all(feature = "time", not(feature = "time-serde-human-readable")),
+    feature = "chrono"
+))]
+macro_rules! impl_time_tuple {
+    ($rust_type: ty, $elm_type: expr, $elm_encoder: expr, $($field: expr),+) => {
+        impl ElmEncode for $rust_type {
+            fn encoder_type() -> String {
+                $elm_encoder.to_string()
+            }
+
+            fn encoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+{encoder} : {elm_type} -> Json.Encode.Value
+{encoder} value =
+    Json.Encode.list Json.Encode.int [ {fields} ]
+",
+                    encoder = $elm_encoder,
+                    elm_type = $elm_type,
+                    fields = [$(::std::format!("value.{}", $field)),+].join(", ")
+                ))
+            }
+        }
+    };
+}
+
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveTime, "String", "Json.Encode.string");
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveDate, "String", "Json.Encode.string");
 #[cfg(feature = "chrono")]
 impl_builtin!(chrono::NaiveDateTime, "String", "Json.Encode.string");
+// FixedOffset doesn't implement serde, so its representation follows Display
+#[cfg(feature = "chrono")]
+impl_builtin!(chrono::FixedOffset, "String", "Json.Encode.string");
 #[cfg(feature = "chrono")]
 impl<T: chrono::TimeZone> ElmEncode for chrono::DateTime<T> {
     fn encoder_type() -> String {
-        String::encoder_type()
+        if posix::enabled() {
+            posix::RFC3339_ENCODER.to_string()
+        } else {
+            String::encoder_type()
+        }
     }
 
     fn encoder_definition() -> Option<String> {
         String::encoder_definition()
     }
 }
+// serialized as ( seconds, nanoseconds ), chrono::TimeDelta is the same type
+#[cfg(feature = "chrono")]
+impl_time_tuple!(
+    chrono::Duration,
+    "TimeDelta",
+    "timeDeltaEncoder",
+    "seconds",
+    "nanoseconds"
+);
+
+// Weekday and Month are serialized with the names of their variants
+#[cfg(feature = "chrono")]
+macro_rules! impl_string_enum {
+    ($rust_type: ty, $elm_type: expr, $elm_encoder: expr, $($variant: expr),+) => {
+        impl ElmEncode for $rust_type {
+            fn encoder_type() -> String {
+                $elm_encoder.to_string()
+            }
+
+            fn encoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+{encoder} : {elm_type} -> Json.Encode.Value
+{encoder} elmRsValue =
+    case elmRsValue of
+        {cases}
+",
+                    encoder = $elm_encoder,
+                    elm_type = $elm_type,
+                    cases = [$(::std::format!("{0} ->\n            Json.Encode.string \"{0}\"", $variant)),+].join("\n        ")
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Weekday,
+    "Weekday",
+    "weekdayEncoder",
+    "Mon",
+    "Tue",
+    "Wed",
+    "Thu",
+    "Fri",
+    "Sat",
+    "Sun"
+);
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Month,
+    "Month",
+    "monthEncoder",
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December"
+);
+
+// without the serde-human-readable feature, time types are serialized as tuples
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(time::Date, "Date", "dateEncoder", "year", "ordinal");
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::Duration,
+    "TimeDuration",
+    "timeDurationEncoder",
+    "seconds",
+    "nanoseconds"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::OffsetDateTime,
+    "OffsetDateTime",
+    "offsetDateTimeEncoder",
+    "year",
+    "ordinal",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond",
+    "offsetHours",
+    "offsetMinutes",
+    "offsetSeconds"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::PrimitiveDateTime,
+    "PrimitiveDateTime",
+    "primitiveDateTimeEncoder",
+    "year",
+    "ordinal",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond"
+);
+#[cfg(all(feature = "time", not(feature = "time-serde-human-readable")))]
+impl_time_tuple!(
+    time::Time,
+    "Time",
+    "timeEncoder",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond"
+);
+
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Date, "String", "Json.Encode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Duration, "String", "Json.Encode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::OffsetDateTime, "String", "Json.Encode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::PrimitiveDateTime, "String", "Json.Encode.string");
+#[cfg(feature = "time-serde-human-readable")]
+impl_builtin!(time::Time, "String", "Json.Encode.string");
+
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+macro_rules! impl_string_backed {
+    ($rust_type: ty, $elm_type: expr, $elm_encoder: expr, $value_encoder: expr) => {
+        impl ElmEncode for $rust_type {
+            fn encoder_type() -> String {
+                $elm_encoder.to_string()
+            }
+
+            fn encoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+{elm_encoder} : {elm_type} -> Json.Encode.Value
+{elm_encoder} ({elm_type} value) =
+    {value_encoder} value
+",
+                    elm_encoder = $elm_encoder,
+                    elm_type = $elm_type,
+                    value_encoder = $value_encoder,
+                ))
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "rust_decimal", not(feature = "rust_decimal-serde-float")))]
+impl_string_backed!(
+    rust_decimal::Decimal,
+    "Decimal",
+    "decimalEncoder",
+    "Json.Encode.string"
+);
+// with serde-float, rust_decimal serializes the value as a number
+#[cfg(feature = "rust_decimal-serde-float")]
+impl_string_backed!(
+    rust_decimal::Decimal,
+    "Decimal",
+    "decimalEncoder",
+    "(String.toFloat >> Maybe.map Json.Encode.float >> Maybe.withDefault Json.Encode.null)"
+);
+#[cfg(feature = "bigdecimal")]
+impl_string_backed!(
+    bigdecimal::BigDecimal,
+    "BigDecimal",
+    "bigDecimalEncoder",
+    "Json.Encode.string"
+);
+
+// num-bigint serializes the magnitude as a list of base 2^32 digits, least significant first,
+// which are calculated from the decimal string one decimal digit at a time
+#[cfg(feature = "num-bigint")]
+const BIG_UINT_DIGITS: &str = r#"        elmRsMultiplyAdd elmRsCarry elmRsDigits =
+            case elmRsDigits of
+                [] ->
+                    if elmRsCarry == 0 then
+                        []
+
+                    else
+                        [ elmRsCarry ]
+
+                elmRsDigit :: elmRsRest ->
+                    let
+                        elmRsValue =
+                            elmRsDigit * 10 + elmRsCarry
+                    in
+                    modBy 4294967296 elmRsValue :: elmRsMultiplyAdd (floor (toFloat elmRsValue / 4294967296)) elmRsRest
+
+        elmRsFromString elmRsString =
+            String.foldl (\elmRsChar elmRsAcc -> elmRsMultiplyAdd (Char.toCode elmRsChar - Char.toCode '0') elmRsAcc) [] elmRsString"#;
+
+#[cfg(feature = "num-bigint")]
+impl ElmEncode for num_bigint::BigUint {
+    fn encoder_type() -> String {
+        "bigUintEncoder".to_string()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        Some(format!(
+            r#"bigUintEncoder : BigUint -> Json.Encode.Value
+bigUintEncoder (BigUint value) =
+    let
+{}
+    in
+    Json.Encode.list Json.Encode.int (elmRsFromString value)
+"#,
+            BIG_UINT_DIGITS
+        ))
+    }
+}
+
+// serialized as ( sign, magnitude ) where the sign is -1, 0 or 1
+#[cfg(feature = "num-bigint")]
+impl ElmEncode for num_bigint::BigInt {
+    fn encoder_type() -> String {
+        "bigIntEncoder".to_string()
+    }
+
+    fn encoder_definition() -> Option<String> {
+        Some(format!(
+            r#"bigIntEncoder : BigInt -> Json.Encode.Value
+bigIntEncoder (BigInt value) =
+    let
+{}
+
+        ( elmRsSign, elmRsMagnitude ) =
+            if String.startsWith "-" value then
+                ( -1, elmRsFromString (String.dropLeft 1 value) )
+
+            else
+                ( 1, elmRsFromString value )
+    in
+    Json.Encode.list identity
+        [ Json.Encode.int
+            (if List.isEmpty elmRsMagnitude then
+                0
+
+             else
+                elmRsSign
+            )
+        , Json.Encode.list Json.Encode.int elmRsMagnitude
+        ]
+"#,
+            BIG_UINT_DIGITS
+        ))
+    }
+}
@@ -0,0 +1,77 @@
+//! Contains the `ElmForm` trait and the `File` marker type.
+
+use crate::{settings::Settings, Elm, ElmQueryField};
+#[cfg(feature = "derive")]
+pub use elm_rs_derive::ElmForm;
+
+/// Used to generate Elm functions that turn a type into an `Http.Body` for form submissions.
+pub trait ElmForm {
+    /// Generates an Elm function named `multipartBody` followed by the name of the type that creates a
+    /// `Http.multipartBody`, and if the type has no file fields, a function named `urlEncodedBody` followed
+    /// by the name of the type that creates an `application/x-www-form-urlencoded` body.
+    fn elm_form() -> String;
+    /// Whether the type has `File` fields, which require `elm/file`.
+    fn form_has_files() -> bool;
+    /// The name of the Elm function that creates an `application/x-www-form-urlencoded` body.
+    fn url_encoded_body_type() -> String;
+}
+
+impl<T: ElmForm + ?Sized> ElmForm for &'_ T {
+    fn elm_form() -> String {
+        T::elm_form()
+    }
+
+    fn form_has_files() -> bool {
+        T::form_has_files()
+    }
+
+    fn url_encoded_body_type() -> String {
+        T::url_encoded_body_type()
+    }
+}
+
+impl<T: ElmForm + ?Sized> ElmForm for &'_ mut T {
+    fn elm_form() -> String {
+        T::elm_form()
+    }
+
+    fn form_has_files() -> bool {
+        T::form_has_files()
+    }
+
+    fn url_encoded_body_type() -> String {
+        T::url_encoded_body_type()
+    }
+}
+
+/// A marker type for file fields in forms, which is a `File` from `elm/file` in Elm and is sent with
+/// `Http.filePart` in multipart forms.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct File;
+
+impl Elm for File {
+    fn elm_type() -> String {
+        "File.File".to_string()
+    }
+
+    fn elm_definition() -> Option<String> {
+        None
+    }
+}
+
+// files can't be turned into strings, so only ElmForm uses the fields
+impl ElmQueryField for File {
+    fn query_field_encoder() -> String {
+        Settings::report_error("`elm_rs::File` can only be used in multipart forms".to_string());
+        "identity".to_string()
+    }
+
+    fn query_field_parser() -> String {
+        Settings::report_error("`elm_rs::File` can only be used in multipart forms".to_string());
+        "Just".to_string()
+    }
+
+    fn query_field_file() -> bool {
+        true
+    }
+}
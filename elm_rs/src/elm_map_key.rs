@@ -0,0 +1,173 @@
+//! Contains the `ElmMapKey` trait.
+
+use crate::{settings::large_integer, Elm};
+#[cfg(feature = "derive")]
+pub use elm_rs_derive::ElmMapKey;
+
+/// Used for the keys of maps, which are serialized as JSON object keys.
+///
+/// Maps with `comparable` keys are represented as a `Dict` in Elm, others as a `List ( key, value )`.
+pub trait ElmMapKey: Elm {
+    /// Whether the Elm type can be used as a `Dict` key.
+    fn comparable() -> bool;
+    /// An Elm function of the type `key -> String` that turns the key into a JSON object key.
+    fn key_encoder() -> String;
+    /// An Elm function of the type `String -> Maybe key` that parses a JSON object key.
+    fn key_decoder() -> String;
+    /// Whether the key is an Elm `String` that is the JSON object key as is,
+    /// in which case maps can be decoded without parsing the keys.
+    fn string_key() -> bool {
+        false
+    }
+}
+
+impl<T: ElmMapKey + ?Sized> ElmMapKey for &'_ T {
+    fn comparable() -> bool {
+        T::comparable()
+    }
+
+    fn key_encoder() -> String {
+        T::key_encoder()
+    }
+
+    fn key_decoder() -> String {
+        T::key_decoder()
+    }
+
+    fn string_key() -> bool {
+        T::string_key()
+    }
+}
+
+macro_rules! impl_for {
+    ($comparable: expr, $key_encoder: expr, $key_decoder: expr, $($t: ty),+) => {
+        $(
+            impl ElmMapKey for $t {
+                fn comparable() -> bool {
+                    $comparable
+                }
+
+                fn key_encoder() -> String {
+                    $key_encoder.to_string()
+                }
+
+                fn key_decoder() -> String {
+                    $key_decoder.to_string()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_string {
+    ($($t: ty),+) => {
+        $(
+            impl ElmMapKey for $t {
+                fn comparable() -> bool {
+                    true
+                }
+
+                fn key_encoder() -> String {
+                    "identity".to_string()
+                }
+
+                fn key_decoder() -> String {
+                    "Just".to_string()
+                }
+
+                fn string_key() -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_string!(String, str);
+#[cfg(feature = "uuid")]
+impl_string!(uuid::Uuid);
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> ElmMapKey for arrayvec::ArrayString<CAP> {
+    fn comparable() -> bool {
+        true
+    }
+
+    fn key_encoder() -> String {
+        "identity".to_string()
+    }
+
+    fn key_decoder() -> String {
+        "Just".to_string()
+    }
+
+    fn string_key() -> bool {
+        true
+    }
+}
+
+impl_for!(
+    true,
+    "String.fromChar",
+    r#"(\elmRsString -> String.uncons elmRsString |> Maybe.andThen (\( elmRsChar, elmRsRest ) -> if String.isEmpty elmRsRest then Just elmRsChar else Nothing))"#,
+    char
+);
+
+impl_for!(
+    true,
+    "String.fromInt",
+    "String.toInt",
+    u8,
+    u16,
+    u32,
+    usize,
+    i8,
+    i16,
+    i32,
+    isize,
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroUsize,
+    std::num::NonZeroI8,
+    std::num::NonZeroI16,
+    std::num::NonZeroI32,
+    std::num::NonZeroIsize
+);
+
+// the representation depends on Settings::large_integers
+macro_rules! impl_large_integer {
+    ($($t: ty),+) => {
+        $(
+            impl ElmMapKey for $t {
+                fn comparable() -> bool {
+                    true
+                }
+
+                fn key_encoder() -> String {
+                    large_integer::key_encoder(stringify!($t))
+                }
+
+                fn key_decoder() -> String {
+                    large_integer::key_decoder(stringify!($t))
+                }
+
+                fn string_key() -> bool {
+                    large_integer::string_key()
+                }
+            }
+        )*
+    };
+}
+
+impl_large_integer!(
+    u64,
+    u128,
+    i64,
+    i128,
+    std::sync::atomic::AtomicU64,
+    std::sync::atomic::AtomicI64,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128
+);
@@ -0,0 +1,43 @@
+//! Contains the `ElmPath` trait.
+
+#[cfg(feature = "derive")]
+pub use elm_rs_derive::ElmPath;
+
+/// Used to generate Elm functions that build URL paths with `Url.Builder.absolute`.
+pub trait ElmPath {
+    /// Generates an Elm function that turns a record of the path parameters into the path,
+    /// including the query if the type has one.
+    fn elm_path() -> String;
+    /// The name of the Elm function generated by `elm_path`.
+    fn path_builder_type() -> String;
+    /// The type of the record taken by the function generated by `elm_path`.
+    fn path_parameters_type() -> String;
+}
+
+impl<T: ElmPath + ?Sized> ElmPath for &'_ T {
+    fn elm_path() -> String {
+        T::elm_path()
+    }
+
+    fn path_builder_type() -> String {
+        T::path_builder_type()
+    }
+
+    fn path_parameters_type() -> String {
+        T::path_parameters_type()
+    }
+}
+
+impl<T: ElmPath + ?Sized> ElmPath for &'_ mut T {
+    fn elm_path() -> String {
+        T::elm_path()
+    }
+
+    fn path_builder_type() -> String {
+        T::path_builder_type()
+    }
+
+    fn path_parameters_type() -> String {
+        T::path_parameters_type()
+    }
+}
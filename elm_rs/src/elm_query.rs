@@ -1,60 +1,666 @@
-//! Contains the `ElmQuery` trait.
+//! Contains the `ElmQuery`, `ElmQueryField` and `ElmQueryParser` traits.
 
+use crate::{
+    settings::{large_integer, Settings},
+    Elm,
+};
 #[cfg(feature = "derive")]
 pub use elm_rs_derive::ElmQuery;
 #[cfg(feature = "derive")]
 pub use elm_rs_derive::ElmQueryField;
+#[cfg(feature = "derive")]
+pub use elm_rs_derive::ElmQueryParser;
 
 /// Used to generate URL encoded key-value pairs in Elm.
 pub trait ElmQuery {
     /// Generates an Elm function that creates a `List Url.Builder.QueryParameter`.
     fn elm_query() -> String;
+    /// The name of the Elm function generated by `elm_query`.
+    fn query_encoder_type() -> String;
+    /// The fields of the type with the Elm expressions for their values, where `value` is the Elm expression
+    /// for the value of the type. Used to nest the type in other queries.
+    #[doc(hidden)]
+    fn query_fields(value: &str) -> Vec<(QueryField, String)>;
 }
 
 impl<T: ElmQuery + ?Sized> ElmQuery for &'_ T {
     fn elm_query() -> String {
         T::elm_query()
     }
+
+    fn query_encoder_type() -> String {
+        T::query_encoder_type()
+    }
+
+    fn query_fields(value: &str) -> Vec<(QueryField, String)> {
+        T::query_fields(value)
+    }
 }
 
 impl<T: ElmQuery + ?Sized> ElmQuery for &'_ mut T {
     fn elm_query() -> String {
         T::elm_query()
     }
+
+    fn query_encoder_type() -> String {
+        T::query_encoder_type()
+    }
+
+    fn query_fields(value: &str) -> Vec<(QueryField, String)> {
+        T::query_fields(value)
+    }
 }
 
-/// Used to generate the fields for `ElmQuery::elm_query`.
-pub trait ElmQueryField {
-    /// The `Url.Builder` type of the field (either `Url.Builder.string` or `Url.Builder.int`).
-    fn query_field_type() -> &'static str;
-    /// The name of the Elm function used to transform it a String or Int for the `query_field_type` (usually `identity`).
-    fn query_field_encoder_name() -> &'static str {
-        "identity"
+/// Used to generate Elm parsers for query strings with `Url.Parser.Query`.
+pub trait ElmQueryParser {
+    /// Generates an Elm definition named `queryParser` followed by the name of the type. For structs it is a
+    /// `Url.Parser.Query.Parser (Maybe T)` that is `Nothing` when a field is missing or invalid,
+    /// for enums a `String -> Url.Parser.Query.Parser (Maybe T)` that parses the parameter with the given key.
+    fn elm_query_parser() -> String;
+    /// The name of the Elm definition generated by `elm_query_parser`.
+    fn query_parser_type() -> String;
+}
+
+impl<T: ElmQueryParser + ?Sized> ElmQueryParser for &'_ T {
+    fn elm_query_parser() -> String {
+        T::elm_query_parser()
     }
+
+    fn query_parser_type() -> String {
+        T::query_parser_type()
+    }
+}
+
+impl<T: ElmQueryParser + ?Sized> ElmQueryParser for &'_ mut T {
+    fn elm_query_parser() -> String {
+        T::elm_query_parser()
+    }
+
+    fn query_parser_type() -> String {
+        T::query_parser_type()
+    }
+}
+
+/// Used to generate the fields for `ElmQuery::elm_query` and `ElmQueryParser::elm_query_parser`.
+pub trait ElmQueryField {
+    /// An Elm function of the type `a -> String` that turns the field's value into the value of the
+    /// query parameter, such as `String.fromInt` or `identity`.
+    fn query_field_encoder() -> String;
     /// If the type needs a custom encoder, this function generates its definition.
     fn query_field_encoder_definition() -> Option<String> {
         None
     }
+    /// An Elm function of the type `String -> Maybe a` that parses the value of a query parameter,
+    /// such as `String.toInt` or `Just`.
+    fn query_field_parser() -> String;
+    /// The Elm value of `Default::default()` for the type, used when a field with `#[serde(default)]`
+    /// is missing from the query.
+    fn query_field_default() -> Option<String> {
+        None
+    }
+    /// Whether the field is left out of the query when it's `Nothing`.
+    fn query_field_optional() -> bool {
+        false
+    }
+    /// Whether the field is a list of values that uses the `query_field_encoder` for each element.
+    fn query_field_list() -> bool {
+        false
+    }
+    /// Whether the field is an `elm_rs::File`, which can only be sent in multipart forms.
+    fn query_field_file() -> bool {
+        false
+    }
 }
 
 impl<T: ElmQueryField + ?Sized> ElmQueryField for &'_ T {
-    fn query_field_type() -> &'static str {
-        T::query_field_type()
+    fn query_field_encoder() -> String {
+        T::query_field_encoder()
+    }
+
+    fn query_field_encoder_definition() -> Option<String> {
+        T::query_field_encoder_definition()
+    }
+
+    fn query_field_parser() -> String {
+        T::query_field_parser()
+    }
+
+    fn query_field_default() -> Option<String> {
+        T::query_field_default()
+    }
+
+    fn query_field_optional() -> bool {
+        T::query_field_optional()
+    }
+
+    fn query_field_list() -> bool {
+        T::query_field_list()
+    }
+
+    fn query_field_file() -> bool {
+        T::query_field_file()
     }
 }
 
 impl<T: ElmQueryField + ?Sized> ElmQueryField for &'_ mut T {
-    fn query_field_type() -> &'static str {
-        T::query_field_type()
+    fn query_field_encoder() -> String {
+        T::query_field_encoder()
+    }
+
+    fn query_field_encoder_definition() -> Option<String> {
+        T::query_field_encoder_definition()
+    }
+
+    fn query_field_parser() -> String {
+        T::query_field_parser()
+    }
+
+    fn query_field_default() -> Option<String> {
+        T::query_field_default()
+    }
+
+    fn query_field_optional() -> bool {
+        T::query_field_optional()
+    }
+
+    fn query_field_list() -> bool {
+        T::query_field_list()
+    }
+
+    fn query_field_file() -> bool {
+        T::query_field_file()
+    }
+}
+
+// None is left out of the query, like serde_urlencoded does when serializing
+impl<T: ElmQueryField> ElmQueryField for Option<T> {
+    fn query_field_encoder() -> String {
+        reject_nested_option::<T>();
+        T::query_field_encoder()
+    }
+
+    fn query_field_encoder_definition() -> Option<String> {
+        T::query_field_encoder_definition()
+    }
+
+    fn query_field_parser() -> String {
+        reject_nested_option::<T>();
+        T::query_field_parser()
+    }
+
+    fn query_field_default() -> Option<String> {
+        Some("Nothing".to_string())
+    }
+
+    fn query_field_optional() -> bool {
+        true
+    }
+
+    fn query_field_list() -> bool {
+        T::query_field_list()
+    }
+
+    fn query_field_file() -> bool {
+        T::query_field_file()
+    }
+}
+
+// a missing parameter can only mean one Nothing, so `Option<Option<T>>` has no representation in a query
+fn reject_nested_option<T: ElmQueryField>() {
+    if T::query_field_optional() {
+        Settings::report_error(format!(
+            "nested options such as `Option<{}>` can't be used in queries",
+            std::any::type_name::<T>()
+        ));
+    }
+}
+
+macro_rules! impl_list {
+    ($($rust_type: ty),+) => {
+        $(
+            impl<T: ElmQueryField> ElmQueryField for $rust_type {
+                fn query_field_encoder() -> String {
+                    T::query_field_encoder()
+                }
+
+                fn query_field_encoder_definition() -> Option<String> {
+                    T::query_field_encoder_definition()
+                }
+
+                fn query_field_parser() -> String {
+                    T::query_field_parser()
+                }
+
+                fn query_field_default() -> Option<String> {
+                    Some("[]".to_string())
+                }
+
+                fn query_field_list() -> bool {
+                    true
+                }
+
+                fn query_field_file() -> bool {
+                    T::query_field_file()
+                }
+            }
+        )*
+    };
+}
+
+impl_list!(
+    Vec<T>,
+    std::collections::VecDeque<T>,
+    [T],
+    std::collections::HashSet<T>,
+    std::collections::BTreeSet<T>
+);
+
+/// A field of a type that derives `ElmQuery` or `ElmQueryParser`. Used by the derive macros.
+#[doc(hidden)]
+pub struct QueryField {
+    name: String,
+    encoder: String,
+    parser: String,
+    default: Option<String>,
+    optional: bool,
+    list: bool,
+    comma_separated: bool,
+    file: bool,
+    // the Rust type of a field with `#[serde(default)]` that has no default value in Elm
+    missing_default: Option<&'static str>,
+    // flattened structs and enums are spliced in with their own encoder and parser
+    flattened: bool,
+    // the fields of a flattened struct, which are needed to nest it, or None for enums
+    flattened_fields: Option<Vec<(QueryField, String)>>,
+}
+
+impl QueryField {
+    /// Reports an error if a field with `#[elm(query_style = "repeated")]` or `#[elm(query_style = "comma")]`
+    /// isn't a list, as the style would have no effect.
+    pub fn check_list_style<T: ElmQueryField + ?Sized>(field: &str, query_style: &str) {
+        if !T::query_field_list() {
+            Settings::report_error(format!(
+                "the {query_style} query style of `{field}` can only be used with lists"
+            ));
+        }
+    }
+
+    /// Reports an error if a path parameter isn't a single value, as optional values, lists and files
+    /// can't be turned into a path segment.
+    pub fn check_path_parameter<T: Elm + ElmQueryField + ?Sized>(parameter: &str) {
+        if T::query_field_optional() || T::query_field_list() || T::query_field_file() {
+            Settings::report_error(format!(
+                "the path parameter `{parameter}` needs to have a single value, but its type is `{}`",
+                T::elm_type()
+            ));
+        }
+    }
+
+    /// `name` is the key in the query and `default` is set for fields with `#[serde(default)]`.
+    pub fn new<T: ElmQueryField + ?Sized>(
+        name: &'static str,
+        comma_separated: bool,
+        default: bool,
+    ) -> Self {
+        let optional = T::query_field_optional();
+        let list = T::query_field_list();
+        let file = T::query_field_file();
+        // files are sent as they are and can't be parsed
+        let (encoder, parser) = if file {
+            (String::new(), String::new())
+        } else {
+            (T::query_field_encoder(), T::query_field_parser())
+        };
+        Self {
+            name: name.to_string(),
+            encoder,
+            parser,
+            // missing optional and list fields are deserialized as None and empty lists
+            default: if optional || list || default {
+                T::query_field_default()
+            } else {
+                None
+            },
+            // reported when the field is parsed, as only parsers use the default
+            missing_default: (default && T::query_field_default().is_none())
+                .then(std::any::type_name::<T>),
+            optional,
+            list,
+            comma_separated,
+            file,
+            flattened: false,
+            flattened_fields: Some(Vec::new()),
+        }
+    }
+
+    /// A field with `#[serde(flatten)]`, whose parameters are spliced in with the `ElmQuery` function of `T`.
+    /// `value` is the Elm expression for the field's value.
+    pub fn flattened<T: ElmQuery + ?Sized>(value: &str) -> Self {
+        Self {
+            encoder: T::query_encoder_type(),
+            flattened_fields: Some(T::query_fields(value)),
+            ..Self::flattened_field()
+        }
+    }
+
+    /// An enum, whose parameters depend on the variant and can only be created with the `ElmQuery` function
+    /// of `T`. Used for `ElmQuery::query_fields` of enums.
+    pub fn enumeration<T: ElmQuery + ?Sized>() -> Self {
+        Self {
+            // only used for errors, as the parameters don't have a single name
+            name: std::any::type_name::<T>().to_string(),
+            encoder: T::query_encoder_type(),
+            flattened_fields: None,
+            ..Self::flattened_field()
+        }
+    }
+
+    /// A field with `#[serde(flatten)]`, which is parsed with the `ElmQueryParser` definition of `T`.
+    pub fn flattened_parser<T: ElmQueryParser + ?Sized>() -> Self {
+        Self {
+            parser: T::query_parser_type(),
+            ..Self::flattened_field()
+        }
+    }
+
+    fn flattened_field() -> Self {
+        Self {
+            name: String::new(),
+            encoder: String::new(),
+            parser: String::new(),
+            default: None,
+            missing_default: None,
+            optional: false,
+            list: false,
+            comma_separated: false,
+            file: false,
+            flattened: true,
+            flattened_fields: Some(Vec::new()),
+        }
+    }
+
+    /// Nests the fields of a struct under `key` like serde_qs, e.g. `filter[name]=x`.
+    pub fn nest(fields: Vec<(Self, String)>, key: &str) -> Vec<(Self, String)> {
+        let mut nested = Vec::new();
+        for (mut field, value) in fields {
+            if field.flattened {
+                // the function of a flattened struct doesn't know the key, so its fields are spliced in instead
+                match field.flattened_fields.take() {
+                    Some(fields) => nested.extend(Self::nest(fields, key)),
+                    None => Settings::report_error(format!(
+                        "the enum `{}` can't be nested with the brackets query style",
+                        field.name
+                    )),
+                }
+            } else {
+                // fields that are already nested keep their brackets: a[b] under key becomes key[a][b]
+                let (head, rest) = field
+                    .name
+                    .split_once('[')
+                    .map(|(head, rest)| (head, format!("[{rest}")))
+                    .unwrap_or((&field.name, String::new()));
+                field.name = format!("{key}[{head}]{rest}");
+                nested.push((field, value));
+            }
+        }
+        nested
+    }
+
+    /// Whether the field is an `elm_rs::File`.
+    pub fn file(&self) -> bool {
+        self.file
+    }
+
+    /// The name of the field and an Elm expression that turns `value` into a string, or None if the field
+    /// can't be a path parameter.
+    #[cfg(feature = "axum")]
+    pub(crate) fn to_path_parameter(&self, value: &str) -> Option<(String, String)> {
+        if self.optional || self.list || self.file || self.flattened {
+            None
+        } else {
+            Some((self.name.clone(), format!("{} {value}", self.encoder)))
+        }
+    }
+
+    // an Elm function from the field's value to a `List Url.Builder.QueryParameter`
+    fn to_parameters(&self) -> String {
+        let Self { name, encoder, .. } = self;
+        self.to_list(
+            &format!("Url.Builder.string \"{name}\" << {encoder}"),
+            &format!("Url.Builder.string \"{name}\""),
+        )
+    }
+
+    // an Elm function from the field's value to a `List Http.Part`
+    fn to_parts(&self) -> String {
+        let Self { name, encoder, .. } = self;
+        if self.file {
+            self.to_list(
+                &format!("Http.filePart \"{name}\""),
+                &format!("Http.filePart \"{name}\""),
+            )
+        } else {
+            self.to_list(
+                &format!("Http.stringPart \"{name}\" << {encoder}"),
+                &format!("Http.stringPart \"{name}\""),
+            )
+        }
+    }
+
+    // an Elm function from the field's value to a list of the elements created by `single` from each value,
+    // where `string` creates an element from a string for comma-joined lists
+    fn to_list(&self, single: &str, string: &str) -> String {
+        let Self { encoder, .. } = self;
+        if !self.list {
+            format!("(List.singleton << {single})")
+        } else if self.comma_separated && !self.file {
+            // the values are joined into a single string which is left out for empty lists
+            format!(
+                r#"(\elmRsValues -> if List.isEmpty elmRsValues then [] else [ {string} (String.join "," (List.map {encoder} elmRsValues)) ])"#
+            )
+        } else {
+            format!("List.map ({single})")
+        }
+    }
+
+    // an Elm parser of the type `Url.Parser.Query.Parser (Maybe a)` for the field
+    fn to_parser(&self) -> String {
+        let Self { name, parser, .. } = self;
+        if self.flattened {
+            return parser.clone();
+        }
+        if let Some(rust_type) = self.missing_default {
+            Settings::report_error(format!(
+                "`{name}` has `#[serde(default)]`, but `{rust_type}` has no default value in Elm"
+            ));
+        }
+        // combines a list of Maybes into a Maybe of a list
+        let combine = "List.foldr (Maybe.map2 (::)) (Just [])";
+        let single = |parser: &str| {
+            format!("if List.length elmRsValues == 1 then Maybe.andThen {parser} (List.head elmRsValues) else Nothing")
+        };
+        let present = if !self.list {
+            single(parser)
+        } else if self.comma_separated {
+            single(&format!(
+                "(String.split \",\" >> List.map {parser} >> {combine})"
+            ))
+        } else {
+            format!("{combine} (List.map {parser} elmRsValues)")
+        };
+        let present = if self.optional {
+            format!("Maybe.map Just ({present})")
+        } else {
+            present
+        };
+        let values = match &self.default {
+            Some(default) => {
+                format!("if List.isEmpty elmRsValues then Just ({default}) else {present}")
+            }
+            None => present,
+        };
+        format!("Url.Parser.Query.custom \"{name}\" (\\elmRsValues -> {values})")
+    }
+}
+
+/// Generates an Elm expression of the type `List Url.Builder.QueryParameter` for the fields.
+/// Used by the derive macro.
+#[doc(hidden)]
+pub fn query_parameters(fields: &[(QueryField, String)]) -> String {
+    report_file_fields(fields.iter().map(|(field, _)| field));
+    if fields
+        .iter()
+        .any(|(field, _)| field.list || field.flattened)
+    {
+        let fields = fields
+            .iter()
+            .map(|(field, value)| {
+                let QueryField { name, encoder, .. } = field;
+                if field.flattened {
+                    format!("{encoder} {value}")
+                } else if field.optional {
+                    format!(
+                        "Maybe.withDefault [] (Maybe.map {} {value})",
+                        field.to_parameters()
+                    )
+                } else if field.list {
+                    format!("{} {value}", field.to_parameters())
+                } else {
+                    format!("[ Url.Builder.string \"{name}\" ({encoder} {value}) ]")
+                }
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "List.concat\n        [ {}\n        ]",
+            fields.join("\n        , ")
+        )
+    } else if fields.iter().any(|(field, _)| field.optional) {
+        // optional parameters are left out when they're Nothing
+        let fields = fields
+            .iter()
+            .map(|(field, value)| {
+                let QueryField { name, encoder, .. } = field;
+                if field.optional {
+                    format!("Maybe.map (Url.Builder.string \"{name}\" << {encoder}) {value}")
+                } else {
+                    format!("Just (Url.Builder.string \"{name}\" ({encoder} {value}))")
+                }
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "List.filterMap identity\n        [ {}\n        ]",
+            fields.join("\n        , ")
+        )
+    } else {
+        let fields = fields
+            .iter()
+            .map(|(field, value)| {
+                let QueryField { name, encoder, .. } = field;
+                format!("Url.Builder.string \"{name}\" ({encoder} {value})")
+            })
+            .collect::<Vec<_>>();
+        format!("[ {} ]", fields.join(", "))
+    }
+}
+
+/// Generates an Elm expression of the type `Url.Parser.Query.Parser (Maybe a)` that applies the
+/// constructor to the fields. Used by the derive macro.
+#[doc(hidden)]
+pub fn query_parser(constructor: &str, fields: &[QueryField]) -> String {
+    report_file_fields(fields);
+    let mut fields = fields.iter();
+    match fields.next() {
+        Some(first) => {
+            let mut parser = format!(
+                "Url.Parser.Query.map (Maybe.map {constructor}) ({})",
+                first.to_parser()
+            );
+            for field in fields {
+                parser.push_str(&format!(
+                    "\n        |> Url.Parser.Query.map2 (Maybe.map2 (|>)) ({})",
+                    field.to_parser()
+                ));
+            }
+            parser
+        }
+        None => format!("Url.Parser.Query.custom \"\" (always (Just {constructor}))"),
+    }
+}
+
+/// Generates an Elm expression of the type `Http.Body` that sends the fields as
+/// `application/x-www-form-urlencoded`. Used by the derive macro.
+#[doc(hidden)]
+pub fn url_encoded_body(fields: &[(QueryField, String)]) -> String {
+    // the parameters are indented under the let
+    format!(
+        "\
+let
+        elmRsParameters =
+            {}
+    in
+    Http.stringBody \"application/x-www-form-urlencoded\" (String.dropLeft 1 (Url.Builder.toQuery elmRsParameters))",
+        query_parameters(fields).replace('\n', "\n        ")
+    )
+}
+
+/// Generates an Elm expression of the type `Http.Body` that sends the fields with `Http.multipartBody`.
+/// Used by the derive macro.
+#[doc(hidden)]
+pub fn multipart_body(fields: &[(QueryField, String)]) -> String {
+    if fields.is_empty() {
+        return "Http.multipartBody []".to_string();
+    }
+    let parts = fields
+        .iter()
+        .map(|(field, value)| {
+            let QueryField { name, encoder, .. } = field;
+            if field.optional {
+                format!(
+                    "Maybe.withDefault [] (Maybe.map {} {value})",
+                    field.to_parts()
+                )
+            } else if field.list {
+                format!("{} {value}", field.to_parts())
+            } else if field.file {
+                format!("[ Http.filePart \"{name}\" {value} ]")
+            } else {
+                format!("[ Http.stringPart \"{name}\" ({encoder} {value}) ]")
+            }
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "Http.multipartBody <|\n        List.concat\n            [ {}\n            ]",
+        parts.join("\n            , ")
+    )
+}
+
+// files can only be sent in multipart forms
+fn report_file_fields<'a>(fields: impl IntoIterator<Item = &'a QueryField>) {
+    for field in fields {
+        if field.file {
+            Settings::report_error(format!(
+                "the file field `{}` can only be used in multipart forms",
+                field.name
+            ));
+        }
     }
 }
 
 macro_rules! impl_for {
-    ($e:expr, $($t:ty),+) => {
+    ($encoder:expr, $parser:expr, $default:expr, $($t:ty),+) => {
         $(
             impl ElmQueryField for $t {
-                fn query_field_type() -> &'static str {
-                    $e
+                fn query_field_encoder() -> String {
+                    $encoder.to_string()
+                }
+
+                fn query_field_parser() -> String {
+                    $parser.to_string()
+                }
+
+                fn query_field_default() -> Option<String> {
+                    let default: Option<&str> = $default;
+                    default.map(String::from)
                 }
             }
         )*
@@ -62,62 +668,322 @@ macro_rules! impl_for {
 }
 
 impl_for!(
-    "Url.Builder.string",
+    "identity",
+    "Just",
+    Some(r#""""#),
     String,
     str,
     std::path::Path,
     std::path::PathBuf
 );
+impl_for!(
+    "identity",
+    "Just",
+    None,
+    std::net::IpAddr,
+    std::net::Ipv4Addr,
+    std::net::Ipv6Addr,
+    std::net::SocketAddr,
+    std::net::SocketAddrV4,
+    std::net::SocketAddrV6
+);
 #[cfg(feature = "uuid")]
-impl_for!("Url.Builder.string", uuid::Uuid);
+impl_for!("identity", "Just", None, uuid::Uuid);
+#[cfg(feature = "url")]
+impl_for!("identity", "Just", None, url::Url);
+#[cfg(feature = "semver")]
+impl_for!("identity", "Just", None, semver::Version);
+#[cfg(feature = "semver")]
+impl_for!("identity", "Just", None, semver::VersionReq);
+#[cfg(feature = "mime")]
+impl_for!("identity", "Just", None, mime::Mime);
+#[cfg(feature = "http")]
+impl_for!("identity", "Just", None, http::Method);
+#[cfg(feature = "http")]
+impl_for!("identity", "Just", None, http::Uri);
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> ElmQueryField for arrayvec::ArrayString<CAP> {
+    fn query_field_encoder() -> String {
+        "identity".to_string()
+    }
+
+    fn query_field_parser() -> String {
+        "Just".to_string()
+    }
+
+    fn query_field_default() -> Option<String> {
+        Some(r#""""#.to_string())
+    }
+}
 #[cfg(feature = "chrono")]
 impl_for!(
-    "Url.Builder.string",
+    "identity",
+    "Just",
+    None,
     chrono::NaiveTime,
     chrono::NaiveDate,
-    chrono::NaiveDateTime
+    chrono::NaiveDateTime,
+    chrono::FixedOffset
 );
 #[cfg(feature = "chrono")]
 impl<T: chrono::TimeZone> ElmQueryField for chrono::DateTime<T> {
-    fn query_field_type() -> &'static str {
-        "Url.Builder.string"
+    fn query_field_encoder() -> String {
+        if crate::posix::enabled() {
+            "posixToRfc3339"
+        } else {
+            "identity"
+        }
+        .to_string()
+    }
+
+    fn query_field_parser() -> String {
+        if crate::posix::enabled() {
+            "posixFromRfc3339"
+        } else {
+            "Just"
+        }
+        .to_string()
     }
 }
 
+#[cfg(feature = "chrono")]
+macro_rules! impl_string_enum {
+    ($rust_type: ty, $elm_type: literal, $($variant: expr),+) => {
+        impl ElmQueryField for $rust_type {
+            fn query_field_encoder() -> String {
+                concat!("queryFieldEncoder", $elm_type).to_string()
+            }
+
+            fn query_field_encoder_definition() -> Option<String> {
+                Some(::std::format!(
+                    "\
+{function_name} : {elm_type} -> String
+{function_name} var =
+    case var of
+        {branches}
+",
+                    function_name = Self::query_field_encoder(),
+                    elm_type = $elm_type,
+                    branches = [$(::std::format!("{0} -> \"{0}\"", $variant)),+].join("\n        ")
+                ))
+            }
+
+            fn query_field_parser() -> String {
+                let mut parser = String::from("(\\elmRsValue -> ");
+                $(
+                    parser.push_str(&::std::format!("if elmRsValue == \"{0}\" then Just {0} else ", $variant));
+                )+
+                parser.push_str("Nothing)");
+                parser
+            }
+        }
+    };
+}
+
+// serialized with the names of the variants
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Weekday,
+    "Weekday",
+    "Mon",
+    "Tue",
+    "Wed",
+    "Thu",
+    "Fri",
+    "Sat",
+    "Sun"
+);
+#[cfg(feature = "chrono")]
+impl_string_enum!(
+    chrono::Month,
+    "Month",
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December"
+);
+// the tuple representation used without serde-human-readable can't be used in queries
+#[cfg(feature = "time-serde-human-readable")]
 impl_for!(
-    "Url.Builder.int",
+    "identity",
+    "Just",
+    None,
+    time::Date,
+    time::Duration,
+    time::OffsetDateTime,
+    time::PrimitiveDateTime,
+    time::Time
+);
+
+impl_for!(
+    "String.fromInt",
+    "String.toInt",
+    Some("0"),
     u8,
     u16,
     u32,
-    u64,
-    u128,
     usize,
     i8,
     i16,
     i32,
-    i64,
-    i128,
     isize,
     std::sync::atomic::AtomicU8,
     std::sync::atomic::AtomicU16,
     std::sync::atomic::AtomicU32,
-    std::sync::atomic::AtomicU64,
     std::sync::atomic::AtomicUsize,
     std::sync::atomic::AtomicI8,
     std::sync::atomic::AtomicI16,
     std::sync::atomic::AtomicI32,
-    std::sync::atomic::AtomicI64,
-    std::sync::atomic::AtomicIsize,
+    std::sync::atomic::AtomicIsize
+);
+impl_for!(
+    "String.fromInt",
+    "String.toInt",
+    None,
     std::num::NonZeroU8,
     std::num::NonZeroU16,
     std::num::NonZeroU32,
-    std::num::NonZeroU64,
-    std::num::NonZeroU128,
     std::num::NonZeroUsize,
     std::num::NonZeroI8,
     std::num::NonZeroI16,
     std::num::NonZeroI32,
-    std::num::NonZeroI64,
-    std::num::NonZeroI128,
     std::num::NonZeroIsize
 );
+
+// the representation depends on Settings::large_integers
+macro_rules! impl_large_integer {
+    ($has_default:expr, $($t:ty),+) => {
+        $(
+            impl ElmQueryField for $t {
+                fn query_field_encoder() -> String {
+                    large_integer::key_encoder(stringify!($t))
+                }
+
+                fn query_field_parser() -> String {
+                    large_integer::key_decoder(stringify!($t))
+                }
+
+                fn query_field_default() -> Option<String> {
+                    $has_default.then(large_integer::query_field_default)
+                }
+            }
+        )*
+    };
+}
+
+impl_large_integer!(
+    true,
+    u64,
+    u128,
+    i64,
+    i128,
+    std::sync::atomic::AtomicU64,
+    std::sync::atomic::AtomicI64
+);
+impl_large_integer!(
+    false,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128
+);
+
+impl_for!("String.fromFloat", "String.toFloat", Some("0"), f32, f64);
+
+impl ElmQueryField for bool {
+    fn query_field_encoder() -> String {
+        r#"(\elmRsBool -> if elmRsBool then "true" else "false")"#.to_string()
+    }
+
+    fn query_field_parser() -> String {
+        r#"(\elmRsBool -> if elmRsBool == "true" then Just True else if elmRsBool == "false" then Just False else Nothing)"#.to_string()
+    }
+
+    fn query_field_default() -> Option<String> {
+        Some("False".to_string())
+    }
+}
+
+impl ElmQueryField for char {
+    fn query_field_encoder() -> String {
+        "String.fromChar".to_string()
+    }
+
+    fn query_field_parser() -> String {
+        r#"(String.uncons >> Maybe.andThen (\( elmRsChar, elmRsRest ) -> if String.isEmpty elmRsRest then Just elmRsChar else Nothing))"#.to_string()
+    }
+}
+
+impl<T: ElmQueryField> ElmQueryField for std::num::Wrapping<T> {
+    fn query_field_encoder() -> String {
+        T::query_field_encoder()
+    }
+
+    fn query_field_encoder_definition() -> Option<String> {
+        T::query_field_encoder_definition()
+    }
+
+    fn query_field_parser() -> String {
+        T::query_field_parser()
+    }
+
+    fn query_field_default() -> Option<String> {
+        T::query_field_default()
+    }
+}
+
+impl<T: ElmQueryField> ElmQueryField for std::cmp::Reverse<T> {
+    fn query_field_encoder() -> String {
+        T::query_field_encoder()
+    }
+
+    fn query_field_encoder_definition() -> Option<String> {
+        T::query_field_encoder_definition()
+    }
+
+    fn query_field_parser() -> String {
+        T::query_field_parser()
+    }
+
+    fn query_field_default() -> Option<String> {
+        T::query_field_default()
+    }
+}
+
+#[cfg(any(
+    feature = "rust_decimal",
+    feature = "bigdecimal",
+    feature = "num-bigint"
+))]
+macro_rules! impl_string_backed {
+    ($rust_type: ty, $elm_type: literal) => {
+        impl ElmQueryField for $rust_type {
+            fn query_field_encoder() -> String {
+                concat!(r#"(\("#, $elm_type, r#" elmRsString) -> elmRsString)"#).to_string()
+            }
+
+            fn query_field_parser() -> String {
+                concat!("(Just << ", $elm_type, ")").to_string()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_string_backed!(rust_decimal::Decimal, "Decimal");
+#[cfg(feature = "bigdecimal")]
+impl_string_backed!(bigdecimal::BigDecimal, "BigDecimal");
+// num-bigint's sign and digit list representation can't be used in queries,
+// so like the large integers these are decimal strings (e.g. with `serde_with::DisplayFromStr`)
+#[cfg(feature = "num-bigint")]
+impl_string_backed!(num_bigint::BigInt, "BigInt");
+#[cfg(feature = "num-bigint")]
+impl_string_backed!(num_bigint::BigUint, "BigUint");
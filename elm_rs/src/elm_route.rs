@@ -0,0 +1,44 @@
+//! Contains the `ElmRoute` trait.
+
+#[cfg(feature = "derive")]
+pub use elm_rs_derive::ElmRoute;
+
+/// Used to generate an Elm `Url.Parser.Parser` for client-side routing and a function that turns
+/// a route back into its path.
+pub trait ElmRoute {
+    /// Generates the parser, named after the type followed by `Parser`, and the function from a route
+    /// to its path, named after the type followed by `ToString`.
+    fn elm_route() -> String;
+    /// The name of the Elm parser generated by `elm_route`.
+    fn route_parser_type() -> String;
+    /// The name of the Elm function from a route to its path generated by `elm_route`.
+    fn route_to_string_type() -> String;
+}
+
+impl<T: ElmRoute + ?Sized> ElmRoute for &'_ T {
+    fn elm_route() -> String {
+        T::elm_route()
+    }
+
+    fn route_parser_type() -> String {
+        T::route_parser_type()
+    }
+
+    fn route_to_string_type() -> String {
+        T::route_to_string_type()
+    }
+}
+
+impl<T: ElmRoute + ?Sized> ElmRoute for &'_ mut T {
+    fn elm_route() -> String {
+        T::elm_route()
+    }
+
+    fn route_parser_type() -> String {
+        T::route_parser_type()
+    }
+
+    fn route_to_string_type() -> String {
+        T::route_to_string_type()
+    }
+}
@@ -0,0 +1,258 @@
+//! Contains the `Endpoint` trait.
+
+use crate::{Elm, ElmDecode, ElmEncode, ElmPath};
+
+/// Describes an HTTP endpoint to generate an Elm function that sends a request to it with `Http.request`.
+/// `()` can be used as the body for requests without a body, as the response for responses without a body
+/// and as the error for endpoints without a JSON error response.
+///
+/// The path, including the query, is built with the `ElmPath` implementation of `Path`. The generated function
+/// uses the path builder, encoder and decoders of the types, so they need to be exported as well.
+///
+/// # Example
+/// ```
+/// use elm_rs::{Elm, ElmDecode, ElmEncode, ElmPath, ElmQuery, Endpoint};
+///
+/// #[derive(Elm, ElmDecode)]
+/// struct Drawing {
+///     title: String,
+/// }
+///
+/// #[derive(Elm, ElmEncode)]
+/// struct NewDrawing {
+///     title: String,
+/// }
+///
+/// #[derive(Elm, ElmQuery)]
+/// struct Options {
+///     thumbnail: bool,
+/// }
+///
+/// #[derive(ElmPath)]
+/// #[elm(path = "/drawings/{id}")]
+/// struct SingleDrawing {
+///     id: i32,
+///     #[elm(query)]
+///     options: Options,
+/// }
+///
+/// struct GetDrawing;
+///
+/// impl Endpoint for GetDrawing {
+///     const NAME: &'static str = "getDrawing";
+///     const METHOD: &'static str = "GET";
+///     type Path = SingleDrawing;
+///     type Body = ();
+///     type Response = Drawing;
+///     type Error = ();
+/// }
+///
+/// assert!(GetDrawing::elm_endpoint().starts_with(
+///     "getDrawing : { id : Int, options : Options } -> (Result Http.Error Drawing -> msg) -> Cmd msg"
+/// ));
+/// ```
+pub trait Endpoint {
+    /// The name of the generated Elm function.
+    const NAME: &'static str;
+    /// The HTTP method, such as `"GET"`.
+    const METHOD: &'static str;
+    /// The path of the endpoint.
+    type Path: ElmPath;
+    /// The request body, which is sent as JSON.
+    type Body: Elm + ElmEncode;
+    /// The response for successful status codes, which is decoded from JSON.
+    type Response: Elm + ElmDecode;
+    /// The response for other status codes, which is decoded from JSON. If it's not `()`, the generated function
+    /// returns an `EndpointError` instead of an `Http.Error`.
+    type Error: Elm + ElmDecode;
+
+    /// Generates an Elm function named `NAME` that takes the record of the path parameters, the body and a
+    /// function that turns the result into a message, and returns the `Cmd` that sends the request.
+    fn elm_endpoint() -> String {
+        endpoint::<Self>()
+    }
+}
+
+fn endpoint<E: Endpoint + ?Sized>() -> String {
+    let path_parameters = E::Path::path_parameters_type();
+    let mut arguments = Vec::new();
+    let url = if path_parameters != "{}" {
+        arguments.push(("elmRsPath".to_string(), path_parameters));
+        format!("{} elmRsPath", E::Path::path_builder_type())
+    } else {
+        format!("{} {{}}", E::Path::path_builder_type())
+    };
+    let body = if is_unit::<E::Body>() {
+        None
+    } else {
+        arguments.push(("elmRsBody".to_string(), E::Body::elm_type()));
+        Some(format!(
+            "Http.jsonBody ({} elmRsBody)",
+            E::Body::encoder_type()
+        ))
+    };
+    RequestFunction {
+        name: E::NAME.to_string(),
+        method: E::METHOD.to_string(),
+        arguments,
+        url,
+        body,
+        response: decoded::<E::Response>()
+            .map(|(elm_type, decoder)| ResponseBody::Json(elm_type, decoder)),
+        error: decoded::<E::Error>(),
+    }
+    .to_elm()
+}
+
+/// An Elm function that sends a request with `Http.request`, taking the arguments followed by a function
+/// that turns the result into a message.
+pub(crate) struct RequestFunction {
+    pub name: String,
+    pub method: String,
+    /// The names and Elm types of the arguments.
+    pub arguments: Vec<(String, String)>,
+    /// An Elm expression for the URL.
+    pub url: String,
+    /// An Elm expression for the `Http.Body`, or None for an empty body.
+    pub body: Option<String>,
+    /// The body of the response, or None for responses without a body.
+    pub response: Option<ResponseBody>,
+    /// The Elm type and decoder of error responses, or None if they're not decoded.
+    pub error: Option<(String, String)>,
+}
+
+/// The body of a successful response.
+pub(crate) enum ResponseBody {
+    /// Decoded from JSON with the Elm type and decoder.
+    Json(String, String),
+    /// Passed on as a `String`.
+    #[cfg_attr(not(feature = "axum"), allow(dead_code))]
+    Text,
+}
+
+impl RequestFunction {
+    pub fn to_elm(&self) -> String {
+        let Self {
+            name,
+            method,
+            arguments,
+            url,
+            body,
+            response,
+            error,
+        } = self;
+        let error_type = match error {
+            Some((error_type, _)) => format!("(EndpointError {})", parenthesize(error_type)),
+            None => "Http.Error".to_string(),
+        };
+        let response_type = match response {
+            Some(ResponseBody::Json(response_type, _)) => parenthesize(response_type),
+            Some(ResponseBody::Text) => "String".to_string(),
+            None => "()".to_string(),
+        };
+        let arguments_types = arguments
+            .iter()
+            .map(|(_, elm_type)| elm_type.clone())
+            .chain([format!("(Result {error_type} {response_type} -> msg)")])
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        let arguments = arguments
+            .iter()
+            .map(|(argument, _)| argument.as_str())
+            .chain(["elmRsToMsg"])
+            .collect::<Vec<_>>()
+            .join(" ");
+        let body = body.as_deref().unwrap_or("Http.emptyBody");
+        let expect = match (error, response) {
+            (Some((_, error_decoder)), _) => {
+                // responses without a body can't be decoded as JSON
+                let parse_response = match response {
+                    Some(ResponseBody::Json(_, decoder)) => format!(
+                        "(Json.Decode.decodeString ({decoder}) >> Result.mapError Json.Decode.errorToString)"
+                    ),
+                    Some(ResponseBody::Text) => "Ok".to_string(),
+                    None => "(\\_ -> Ok ())".to_string(),
+                };
+                format!("expectEndpoint elmRsToMsg ({error_decoder}) {parse_response}")
+            }
+            (None, Some(ResponseBody::Json(_, decoder))) => {
+                format!("Http.expectJson elmRsToMsg ({decoder})")
+            }
+            (None, Some(ResponseBody::Text)) => "Http.expectString elmRsToMsg".to_string(),
+            (None, None) => "Http.expectWhatever elmRsToMsg".to_string(),
+        };
+
+        format!(
+            "\
+{name} : {arguments_types} -> Cmd msg
+{name} {arguments} =
+    Http.request
+        {{ method = {method:?}
+        , headers = []
+        , url = {url}
+        , body = {body}
+        , expect = {expect}
+        , timeout = Nothing
+        , tracker = Nothing
+        }}
+"
+        )
+    }
+}
+
+/// The definitions shared by the generated endpoint functions. Used by the `export!` macro.
+#[doc(hidden)]
+pub fn endpoint_definitions() -> &'static str {
+    r#"type EndpointError e
+    = EndpointHttpError Http.Error
+    | EndpointErrorResponse Http.Metadata e
+
+
+expectEndpoint : (Result (EndpointError e) a -> msg) -> Json.Decode.Decoder e -> (String -> Result String a) -> Http.Expect msg
+expectEndpoint elmRsToMsg elmRsErrorDecoder elmRsParseResponse =
+    Http.expectStringResponse elmRsToMsg <|
+        \elmRsResponse ->
+            case elmRsResponse of
+                Http.BadUrl_ elmRsUrl ->
+                    Err (EndpointHttpError (Http.BadUrl elmRsUrl))
+
+                Http.Timeout_ ->
+                    Err (EndpointHttpError Http.Timeout)
+
+                Http.NetworkError_ ->
+                    Err (EndpointHttpError Http.NetworkError)
+
+                Http.BadStatus_ elmRsMetadata elmRsBody ->
+                    case Json.Decode.decodeString elmRsErrorDecoder elmRsBody of
+                        Ok elmRsError ->
+                            Err (EndpointErrorResponse elmRsMetadata elmRsError)
+
+                        Err _ ->
+                            Err (EndpointHttpError (Http.BadStatus elmRsMetadata.statusCode))
+
+                Http.GoodStatus_ _ elmRsBody ->
+                    Result.mapError (EndpointHttpError << Http.BadBody) (elmRsParseResponse elmRsBody)
+"#
+}
+
+fn is_unit<T: Elm + ?Sized>() -> bool {
+    T::elm_type() == "()"
+}
+
+// the Elm type and decoder of a response, or None for `()`
+fn decoded<T: Elm + ElmDecode + ?Sized>() -> Option<(String, String)> {
+    if is_unit::<T>() {
+        None
+    } else {
+        Some((T::elm_type(), T::decoder_type()))
+    }
+}
+
+// wraps types with arguments in parentheses so that they can be used as arguments themselves
+fn parenthesize(elm_type: &str) -> String {
+    if elm_type.contains(' ') {
+        format!("({elm_type})")
+    } else {
+        elm_type.to_string()
+    }
+}
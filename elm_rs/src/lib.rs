@@ -1,9 +1,19 @@
 #![cfg_attr(doc, doc = include_str!("../README.md"))]
 
+#[cfg(feature = "axum")]
+pub mod axum;
 mod elm;
 mod elm_decode;
 mod elm_encode;
+mod elm_form;
+mod elm_map_key;
+mod elm_path;
 mod elm_query;
+mod elm_route;
+mod endpoint;
+#[doc(hidden)]
+pub mod posix;
+mod settings;
 #[cfg(test)]
 mod test;
 
@@ -14,11 +24,45 @@ pub use self::{
     elm::Elm,
     elm_decode::ElmDecode,
     elm_encode::ElmEncode,
-    elm_query::{ElmQuery, ElmQueryField},
+    elm_form::{ElmForm, File},
+    elm_map_key::ElmMapKey,
+    elm_path::ElmPath,
+    elm_query::{ElmQuery, ElmQueryField, ElmQueryParser},
+    elm_route::ElmRoute,
+    endpoint::Endpoint,
+    settings::{LargeIntegers, Settings},
 };
+#[doc(hidden)]
+pub use self::{
+    elm_query::{multipart_body, query_parameters, query_parser, url_encoded_body, QueryField},
+    endpoint::endpoint_definitions,
+    settings::{large_integers, WithLargeIntegers},
+};
+
+/// Writes the definition unless an identical one has already been written. Used by the `export!` macro.
+/// Definitions are compared by their contents, as generic types such as the larger tuples generate
+/// the same definitions for every instantiation.
+#[doc(hidden)]
+pub fn write_definition(
+    target: &mut impl std::io::Write,
+    generated_elm_definitions: &mut std::collections::HashSet<String>,
+    definition: Option<String>,
+) -> std::io::Result<()> {
+    if let Some(definition) = definition {
+        if !generated_elm_definitions.contains(&definition) {
+            writeln!(target, "{}\n", definition)?;
+            generated_elm_definitions.insert(definition);
+        }
+    }
+    Ok(())
+}
 
 #[macro_export]
-/// Writes an Elm module to the target. Assumes `elm/json` and `elm/http` are installed.
+/// Writes an Elm module to the target. Assumes `elm/json`, `elm/http` and `elm/url` are installed,
+/// as well as `elm/time` if `Settings::posix_time` is enabled and `elm/file` if a form has `File` fields.
+///
+/// The optional `settings` key takes a `Settings` value that is active while the module is generated.
+/// With the `axum` feature, the `routers` key takes `elm_rs::axum::ElmRouter` values.
 ///
 /// # Example
 /// ```no_run
@@ -26,6 +70,9 @@ pub use self::{
 /// ```
 macro_rules! export {
     ($name: expr, $target: expr, {
+        $(
+            settings:   $settings: expr,
+        )?
         $(
             encoders:   [ $($encode: ty),*   $(,)? ] $(,)?
         )?
@@ -38,9 +85,38 @@ macro_rules! export {
         $(
             query_fields:    [ $($query_field: ty),*    $(,)? ] $(,)?
         )?
+        $(
+            query_parsers:    [ $($query_parser: ty),*    $(,)? ] $(,)?
+        )?
+        $(
+            paths:    [ $($path: ty),*    $(,)? ] $(,)?
+        )?
+        $(
+            routes:    [ $($route: ty),*    $(,)? ] $(,)?
+        )?
+        $(
+            forms:    [ $($form: ty),*    $(,)? ] $(,)?
+        )?
+        $(
+            endpoints:    [ $($endpoint: ty),*    $(,)? ] $(,)?
+        )?
+        $(
+            routers:    [ $($router: expr),*    $(,)? ] $(,)?
+        )?
     }) => {
         {
-            fn _export(name: &::std::primitive::str, target: &mut impl ::std::io::Write) -> ::std::result::Result<(), ::std::io::Error> {
+            // the routers are evaluated in closures so that they are exported with the settings
+            fn _export(
+                name: &::std::primitive::str,
+                target: &mut impl ::std::io::Write,
+                routers: &[&dyn ::std::ops::Fn() -> ::std::vec::Vec<::std::string::String>],
+            ) -> ::std::result::Result<(), ::std::io::Error> {
+                // File is only imported when it's needed, as it requires elm/file
+                #[allow(unused_mut)]
+                let mut uses_files = false;
+                $($(
+                    uses_files |= <$form as $crate::ElmForm>::form_has_files();
+                )*)?
                 ::std::writeln!(target, r#"
 -- generated by elm_rs
 
@@ -48,10 +124,13 @@ macro_rules! export {
 module {} exposing (..)
 
 import Dict exposing (Dict)
-import Http
+{}import Http
 import Json.Decode
 import Json.Encode
+{}import Url
 import Url.Builder
+import Url.Parser exposing ((</>))
+import Url.Parser.Query
 
 
 {}
@@ -61,56 +140,78 @@ import Url.Builder
 
 "#,
                     name,
+                    if uses_files { "import File\n" } else { "" },
+                    $crate::posix::import(),
                     <::std::result::Result::<(), ()> as $crate::ElmEncode>::encoder_definition().unwrap(),
                     <::std::result::Result::<(), ()> as $crate::ElmDecode>::decoder_definition().unwrap(),
                 )?;
-                let mut generated_elm_definitions = ::std::collections::HashSet::<&str>::new();
+                if let ::std::option::Option::Some(posix_definitions) = $crate::posix::definitions() {
+                    ::std::writeln!(target, "{}\n", posix_definitions)?;
+                }
+                // unused when only paths and endpoints are exported
+                #[allow(unused_mut, unused_variables)]
+                let mut generated_elm_definitions = ::std::collections::HashSet::<::std::string::String>::new();
                 $($(
-                    if !generated_elm_definitions.contains(stringify!($encode)) {
-                        generated_elm_definitions.insert(stringify!($encode));
-                        if let ::std::option::Option::Some(elm_definition) = <$encode as $crate::Elm>::elm_definition() {
-                            ::std::writeln!(target, "{}\n", elm_definition)?;
-                        }
-                    }
-                    if let ::std::option::Option::Some(encoder_definition) = <$encode as $crate::ElmEncode>::encoder_definition() {
-                        ::std::writeln!(target, "{}\n", encoder_definition)?;
-                    }
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$encode as $crate::Elm>::elm_definition())?;
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$encode as $crate::ElmEncode>::encoder_definition())?;
                 )*)?
                 $($(
-                    if !generated_elm_definitions.contains(stringify!($decode)) {
-                        generated_elm_definitions.insert(stringify!($decode));
-                        if let ::std::option::Option::Some(elm_definition) = <$decode as $crate::Elm>::elm_definition() {
-                            ::std::writeln!(target, "{}\n", elm_definition)?;
-                        }
-                    }
-                    if let ::std::option::Option::Some(decoder_definition) = <$decode as $crate::ElmDecode>::decoder_definition() {
-                        ::std::writeln!(target, "{}\n", decoder_definition)?;
-                    }
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$decode as $crate::Elm>::elm_definition())?;
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$decode as $crate::ElmDecode>::decoder_definition())?;
                 )*)?
                 $($(
-                    if !generated_elm_definitions.contains(stringify!($query)) {
-                        generated_elm_definitions.insert(stringify!($query));
-                        if let ::std::option::Option::Some(elm_definition) = <$query as $crate::Elm>::elm_definition() {
-                            ::std::writeln!(target, "{}\n", elm_definition)?;
-                        }
-                    }
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$query as $crate::Elm>::elm_definition())?;
                     let query_definition = <$query as $crate::ElmQuery>::elm_query();
                     ::std::writeln!(target, "{}\n", query_definition)?;
                 )*)?
                 $($(
-                    if !generated_elm_definitions.contains(stringify!($query_field)) {
-                        generated_elm_definitions.insert(stringify!($query_field));
-                        if let ::std::option::Option::Some(elm_definition) = <$query_field as $crate::Elm>::elm_definition() {
-                            ::std::writeln!(target, "{}\n", elm_definition)?;
-                        }
-                    }
-                    if let Some(query_field_encoder_definition) = <$query_field as $crate::ElmQueryField>::query_field_encoder_definition() {
-                        ::std::writeln!(target, "{}\n", query_field_encoder_definition)?;
-                    }
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$query_field as $crate::Elm>::elm_definition())?;
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$query_field as $crate::ElmQueryField>::query_field_encoder_definition())?;
+                )*)?
+                $($(
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$query_parser as $crate::Elm>::elm_definition())?;
+                    let query_parser_definition = <$query_parser as $crate::ElmQueryParser>::elm_query_parser();
+                    ::std::writeln!(target, "{}\n", query_parser_definition)?;
+                )*)?
+                $($(
+                    let path_definition = <$path as $crate::ElmPath>::elm_path();
+                    ::std::writeln!(target, "{}\n", path_definition)?;
+                )*)?
+                $($(
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$route as $crate::Elm>::elm_definition())?;
+                    let route_definition = <$route as $crate::ElmRoute>::elm_route();
+                    ::std::writeln!(target, "{}\n", route_definition)?;
                 )*)?
+                $($(
+                    $crate::write_definition(target, &mut generated_elm_definitions, <$form as $crate::Elm>::elm_definition())?;
+                    let form_definition = <$form as $crate::ElmForm>::elm_form();
+                    ::std::writeln!(target, "{}\n", form_definition)?;
+                )*)?
+                #[allow(unused_mut)]
+                let mut endpoint_definitions = ::std::vec::Vec::<::std::string::String>::new();
+                $($(
+                    endpoint_definitions.push(<$endpoint as $crate::Endpoint>::elm_endpoint());
+                )*)?
+                for router in routers {
+                    ::std::iter::Extend::extend(&mut endpoint_definitions, router());
+                }
+                if !endpoint_definitions.is_empty() {
+                    ::std::writeln!(target, "{}\n", $crate::endpoint_definitions())?;
+                    for endpoint_definition in endpoint_definitions {
+                        ::std::writeln!(target, "{}\n", endpoint_definition)?;
+                    }
+                }
                 ::std::result::Result::Ok(())
             }
-            _export($name, $target)
+            #[allow(unused_variables)]
+            let settings = $crate::Settings::current();
+            $(
+                let settings = $settings;
+            )?
+            let routers: &[&dyn ::std::ops::Fn() -> ::std::vec::Vec<::std::string::String>] = &[$($(
+                &|| $crate::axum::ElmRouter::elm_endpoints(&$router),
+            )*)?];
+            $crate::Settings::scope(settings, || $crate::Settings::__export(|| _export($name, $target, routers)))
         }
     };
 }
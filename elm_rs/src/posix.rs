@@ -0,0 +1,388 @@
+//! Contains the `Time.Posix` representation used with `Settings::posix_time`.
+
+use crate::{Elm, ElmDecode, ElmEncode, ElmQueryField, Settings};
+use std::marker::PhantomData;
+
+pub(crate) fn enabled() -> bool {
+    Settings::current().posix_time
+}
+
+/// The import for elm/time if it's needed with the current settings.
+#[doc(hidden)]
+pub fn import() -> &'static str {
+    if enabled() {
+        "import Time\n"
+    } else {
+        ""
+    }
+}
+
+/// The helper functions used by the `Time.Posix` encoders and decoders if they're needed with the current settings.
+#[doc(hidden)]
+pub fn definitions() -> Option<String> {
+    if enabled() {
+        Some(format!("{}\n\n{}", TO_RFC3339, FROM_RFC3339))
+    } else {
+        None
+    }
+}
+
+pub(crate) const RFC3339_ENCODER: &str = "(Json.Encode.string << posixToRfc3339)";
+pub(crate) const RFC3339_DECODER: &str = r#"(Json.Decode.string |> Json.Decode.andThen (posixFromRfc3339 >> Maybe.map Json.Decode.succeed >> Maybe.withDefault (Json.Decode.fail "Invalid RFC 3339 timestamp")))"#;
+
+// SystemTime is serialized as { secs_since_epoch, nanos_since_epoch }
+pub(crate) const SYSTEM_TIME_ENCODER: &str = r#"(\elmRsPosix -> Json.Encode.object [ ( "secs_since_epoch", Json.Encode.int (floor (toFloat (Time.posixToMillis elmRsPosix) / 1000)) ), ( "nanos_since_epoch", Json.Encode.int (modBy 1000 (Time.posixToMillis elmRsPosix) * 1000000) ) ])"#;
+pub(crate) const SYSTEM_TIME_DECODER: &str = r#"(Json.Decode.map2 (\elmRsSecs elmRsNanos -> Time.millisToPosix (elmRsSecs * 1000 + elmRsNanos // 1000000)) (Json.Decode.field "secs_since_epoch" Json.Decode.int) (Json.Decode.field "nanos_since_epoch" Json.Decode.int))"#;
+
+const TO_RFC3339: &str = r#"posixToRfc3339 : Time.Posix -> String
+posixToRfc3339 elmRsPosix =
+    let
+        elmRsPad elmRsLength elmRsInt =
+            String.padLeft elmRsLength '0' (String.fromInt elmRsInt)
+
+        elmRsMonth =
+            case Time.toMonth Time.utc elmRsPosix of
+                Time.Jan ->
+                    1
+
+                Time.Feb ->
+                    2
+
+                Time.Mar ->
+                    3
+
+                Time.Apr ->
+                    4
+
+                Time.May ->
+                    5
+
+                Time.Jun ->
+                    6
+
+                Time.Jul ->
+                    7
+
+                Time.Aug ->
+                    8
+
+                Time.Sep ->
+                    9
+
+                Time.Oct ->
+                    10
+
+                Time.Nov ->
+                    11
+
+                Time.Dec ->
+                    12
+    in
+    elmRsPad 4 (Time.toYear Time.utc elmRsPosix)
+        ++ "-"
+        ++ elmRsPad 2 elmRsMonth
+        ++ "-"
+        ++ elmRsPad 2 (Time.toDay Time.utc elmRsPosix)
+        ++ "T"
+        ++ elmRsPad 2 (Time.toHour Time.utc elmRsPosix)
+        ++ ":"
+        ++ elmRsPad 2 (Time.toMinute Time.utc elmRsPosix)
+        ++ ":"
+        ++ elmRsPad 2 (Time.toSecond Time.utc elmRsPosix)
+        ++ "."
+        ++ elmRsPad 3 (Time.toMillis Time.utc elmRsPosix)
+        ++ "Z""#;
+
+// the days are counted with the days_from_civil algorithm from http://howardhinnant.github.io/date_algorithms.html
+// and fractions of seconds beyond milliseconds are truncated
+const FROM_RFC3339: &str = r#"posixFromRfc3339 : String -> Maybe Time.Posix
+posixFromRfc3339 elmRsString =
+    let
+        elmRsLength =
+            String.length elmRsString
+
+        elmRsSlice elmRsStart elmRsEnd =
+            String.slice elmRsStart elmRsEnd elmRsString
+
+        elmRsIsSeparator elmRsIndex elmRsSeparators =
+            String.length (elmRsSlice elmRsIndex (elmRsIndex + 1)) == 1 && String.contains (elmRsSlice elmRsIndex (elmRsIndex + 1)) elmRsSeparators
+
+        elmRsToDigits elmRsPart =
+            if not (String.isEmpty elmRsPart) && String.all Char.isDigit elmRsPart then
+                String.toInt elmRsPart
+
+            else
+                Nothing
+
+        elmRsDigits elmRsStart elmRsEnd =
+            if String.length (elmRsSlice elmRsStart elmRsEnd) == elmRsEnd - elmRsStart then
+                elmRsToDigits (elmRsSlice elmRsStart elmRsEnd)
+
+            else
+                Nothing
+
+        elmRsInRange elmRsMin elmRsMax =
+            Maybe.andThen
+                (\elmRsInt ->
+                    if elmRsMin <= elmRsInt && elmRsInt <= elmRsMax then
+                        Just elmRsInt
+
+                    else
+                        Nothing
+                )
+
+        elmRsParsedDays =
+            Maybe.map3
+                (\elmRsYear elmRsMonth elmRsDay ->
+                    let
+                        elmRsShiftedYear =
+                            if elmRsMonth <= 2 then
+                                elmRsYear - 1
+
+                            else
+                                elmRsYear
+
+                        elmRsEra =
+                            floor (toFloat elmRsShiftedYear / 400)
+
+                        elmRsYearOfEra =
+                            elmRsShiftedYear - elmRsEra * 400
+
+                        elmRsDayOfYear =
+                            (153 * modBy 12 (elmRsMonth + 9) + 2) // 5 + elmRsDay - 1
+
+                        elmRsDayOfEra =
+                            elmRsYearOfEra * 365 + elmRsYearOfEra // 4 - elmRsYearOfEra // 100 + elmRsDayOfYear
+                    in
+                    elmRsEra * 146097 + elmRsDayOfEra - 719468
+                )
+                (elmRsDigits 0 4)
+                (elmRsInRange 1 12 (elmRsDigits 5 7))
+                (elmRsInRange 1 31 (elmRsDigits 8 10))
+
+        elmRsParsedSeconds =
+            Maybe.map3
+                (\elmRsHour elmRsMinute elmRsSecond -> elmRsHour * 3600 + elmRsMinute * 60 + elmRsSecond)
+                (elmRsInRange 0 23 (elmRsDigits 11 13))
+                (elmRsInRange 0 59 (elmRsDigits 14 16))
+                (elmRsInRange 0 60 (elmRsDigits 17 19))
+
+        ( elmRsFraction, elmRsParsedOffset ) =
+            if String.endsWith "Z" elmRsString || String.endsWith "z" elmRsString then
+                ( elmRsSlice 19 (elmRsLength - 1), Just 0 )
+
+            else if elmRsIsSeparator (elmRsLength - 3) ":" then
+                ( elmRsSlice 19 (elmRsLength - 6)
+                , Maybe.map2 (\elmRsHours elmRsMinutes -> elmRsHours * 60 + elmRsMinutes)
+                    (elmRsInRange 0 23 (elmRsDigits (elmRsLength - 5) (elmRsLength - 3)))
+                    (elmRsInRange 0 59 (elmRsDigits (elmRsLength - 2) elmRsLength))
+                    |> Maybe.andThen
+                        (\elmRsMinutes ->
+                            case elmRsSlice (elmRsLength - 6) (elmRsLength - 5) of
+                                "+" ->
+                                    Just elmRsMinutes
+
+                                "-" ->
+                                    Just (negate elmRsMinutes)
+
+                                _ ->
+                                    Nothing
+                        )
+                )
+
+            else
+                ( "", Nothing )
+
+        elmRsParsedMillis =
+            if String.isEmpty elmRsFraction then
+                Just 0
+
+            else if String.startsWith "." elmRsFraction && elmRsToDigits (String.dropLeft 1 elmRsFraction) /= Nothing then
+                String.toInt (String.left 3 (String.padRight 3 '0' (String.dropLeft 1 elmRsFraction)))
+
+            else
+                Nothing
+    in
+    if List.all (\( elmRsIndex, elmRsSeparators ) -> elmRsIsSeparator elmRsIndex elmRsSeparators) [ ( 4, "-" ), ( 7, "-" ), ( 10, "Tt " ), ( 13, ":" ), ( 16, ":" ) ] then
+        Maybe.map4
+            (\elmRsDays elmRsSeconds elmRsMillis elmRsOffset -> Time.millisToPosix ((elmRsDays * 86400 + elmRsSeconds - elmRsOffset * 60) * 1000 + elmRsMillis))
+            elmRsParsedDays
+            elmRsParsedSeconds
+            elmRsParsedMillis
+            elmRsParsedOffset
+
+    else
+        Nothing"#;
+
+/// Generates the Elm code for fields with the known RFC 3339 `#[serde(with = "..")]` modules.
+/// Used by the derive macros.
+#[doc(hidden)]
+pub struct Rfc3339;
+
+/// Generates the Elm code for fields with the known timestamp `#[serde(with = "..")]` modules.
+/// `I` is the integer type the timestamp is serialized as, which is used when `posix_time` is disabled.
+/// Used by the derive macros.
+#[doc(hidden)]
+pub struct Timestamp<U, I = i64>(PhantomData<U>, PhantomData<I>);
+
+/// The unit of a `Timestamp`.
+#[doc(hidden)]
+pub trait Unit {
+    /// An Elm function of the type `Time.Posix -> Int`.
+    const FROM_POSIX: &'static str;
+    /// An Elm function of the type `Int -> Time.Posix`.
+    const TO_POSIX: &'static str;
+}
+
+#[doc(hidden)]
+pub struct Seconds;
+#[doc(hidden)]
+pub struct Milliseconds;
+#[doc(hidden)]
+pub struct Microseconds;
+#[doc(hidden)]
+pub struct Nanoseconds;
+
+impl Unit for Seconds {
+    const FROM_POSIX: &'static str =
+        r#"(\elmRsPosix -> floor (toFloat (Time.posixToMillis elmRsPosix) / 1000))"#;
+    const TO_POSIX: &'static str =
+        r#"(\elmRsTimestamp -> Time.millisToPosix (elmRsTimestamp * 1000))"#;
+}
+
+impl Unit for Milliseconds {
+    const FROM_POSIX: &'static str = "Time.posixToMillis";
+    const TO_POSIX: &'static str = "Time.millisToPosix";
+}
+
+impl Unit for Microseconds {
+    const FROM_POSIX: &'static str = r#"(\elmRsPosix -> Time.posixToMillis elmRsPosix * 1000)"#;
+    const TO_POSIX: &'static str =
+        r#"(\elmRsTimestamp -> Time.millisToPosix (floor (toFloat elmRsTimestamp / 1000)))"#;
+}
+
+impl Unit for Nanoseconds {
+    const FROM_POSIX: &'static str = r#"(\elmRsPosix -> Time.posixToMillis elmRsPosix * 1000000)"#;
+    const TO_POSIX: &'static str =
+        r#"(\elmRsTimestamp -> Time.millisToPosix (floor (toFloat elmRsTimestamp / 1000000)))"#;
+}
+
+impl Elm for Rfc3339 {
+    fn elm_type() -> String {
+        if enabled() {
+            "Time.Posix".to_string()
+        } else {
+            String::elm_type()
+        }
+    }
+
+    fn elm_definition() -> Option<String> {
+        None
+    }
+}
+
+impl ElmEncode for Rfc3339 {
+    fn encoder_type() -> String {
+        if enabled() {
+            RFC3339_ENCODER.to_string()
+        } else {
+            String::encoder_type()
+        }
+    }
+
+    fn encoder_definition() -> Option<String> {
+        None
+    }
+}
+
+impl ElmDecode for Rfc3339 {
+    fn decoder_type() -> String {
+        if enabled() {
+            RFC3339_DECODER.to_string()
+        } else {
+            String::decoder_type()
+        }
+    }
+
+    fn decoder_definition() -> Option<String> {
+        None
+    }
+}
+
+impl ElmQueryField for Rfc3339 {
+    fn query_field_encoder() -> String {
+        if enabled() {
+            "posixToRfc3339".to_string()
+        } else {
+            String::query_field_encoder()
+        }
+    }
+
+    fn query_field_parser() -> String {
+        if enabled() {
+            "posixFromRfc3339".to_string()
+        } else {
+            String::query_field_parser()
+        }
+    }
+}
+
+impl<U: Unit, I: Elm> Elm for Timestamp<U, I> {
+    fn elm_type() -> String {
+        if enabled() {
+            "Time.Posix".to_string()
+        } else {
+            I::elm_type()
+        }
+    }
+
+    fn elm_definition() -> Option<String> {
+        None
+    }
+}
+
+impl<U: Unit, I: ElmEncode> ElmEncode for Timestamp<U, I> {
+    fn encoder_type() -> String {
+        if enabled() {
+            format!("(Json.Encode.int << {})", U::FROM_POSIX)
+        } else {
+            I::encoder_type()
+        }
+    }
+
+    fn encoder_definition() -> Option<String> {
+        None
+    }
+}
+
+impl<U: Unit, I: ElmDecode> ElmDecode for Timestamp<U, I> {
+    fn decoder_type() -> String {
+        if enabled() {
+            format!("(Json.Decode.map {} Json.Decode.int)", U::TO_POSIX)
+        } else {
+            I::decoder_type()
+        }
+    }
+
+    fn decoder_definition() -> Option<String> {
+        None
+    }
+}
+
+impl<U: Unit, I: ElmQueryField> ElmQueryField for Timestamp<U, I> {
+    fn query_field_encoder() -> String {
+        if enabled() {
+            format!("(String.fromInt << {})", U::FROM_POSIX)
+        } else {
+            I::query_field_encoder()
+        }
+    }
+
+    fn query_field_parser() -> String {
+        if enabled() {
+            format!("(String.toInt >> Maybe.map {})", U::TO_POSIX)
+        } else {
+            I::query_field_parser()
+        }
+    }
+}
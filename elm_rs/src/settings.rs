@@ -0,0 +1,327 @@
+//! Contains the `Settings` type.
+
+use crate::{Elm, ElmDecode, ElmEncode, ElmMapKey, ElmQueryField};
+use std::{cell::RefCell, collections::BTreeSet, io, marker::PhantomData};
+
+thread_local! {
+    static CURRENT: RefCell<Settings> = RefCell::new(Settings::default());
+    // errors reported while generating code, returned by export!
+    // None outside of exports, so that generating code manually doesn't leak errors into the next export
+    static ERRORS: RefCell<Option<BTreeSet<String>>> = RefCell::new(None);
+}
+
+/// Generator-wide settings that affect the generated Elm code.
+///
+/// The settings can be passed to `export!` or activated manually with `Settings::scope`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    /// Makes all generated decoders for records fail on unknown fields,
+    /// as if every type had the `#[serde(deny_unknown_fields)]` attribute.
+    pub deny_unknown_fields: bool,
+    /// How 64 and 128-bit integers are represented in Elm.
+    /// Can be overridden for a single field with `#[elm(large_integers = "..")]`.
+    pub large_integers: LargeIntegers,
+    /// Represents `IndexMap`s as a `List ( key, value )` that keeps the order of the entries
+    /// instead of as a `Dict`, which sorts them by key.
+    pub ordered_index_maps: bool,
+    /// Represents `SystemTime`, chrono's `DateTime` and fields with the RFC 3339 and timestamp serde modules
+    /// of time and chrono as `Time.Posix` instead of as records, strings or integers. Requires `elm/time`.
+    pub posix_time: bool,
+}
+
+/// How `u64`, `i64`, `u128`, `i128` and the corresponding `NonZero` and atomic types are represented in Elm.
+///
+/// In the browser, Elm's `Int` is a JavaScript number that can only represent integers up to 2^53 exactly.
+/// Larger values are silently rounded when they are decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LargeIntegers {
+    /// Represented as `Int` without any checks.
+    Int,
+    /// Represented as `Int` with decoders that fail for values that can't be represented exactly.
+    Checked,
+    /// Represented as `String`, for backends that serialize them as strings
+    /// (e.g. with `serde_with::DisplayFromStr`).
+    String,
+    /// Makes `export!` fail when these types are used.
+    Deny,
+}
+
+impl Default for LargeIntegers {
+    fn default() -> Self {
+        Self::Int
+    }
+}
+
+impl Settings {
+    /// The settings that are currently active.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Activates the settings for the duration of the given function.
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<Settings>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                if let Some(previous) = self.0.take() {
+                    CURRENT.with(|current| *current.borrow_mut() = previous);
+                }
+            }
+        }
+
+        let previous = CURRENT.with(|current| current.replace(self));
+        let _restore = Restore(Some(previous));
+        f()
+    }
+
+    /// Runs the export and turns the errors reported during it into an error.
+    #[doc(hidden)]
+    pub fn __export(f: impl FnOnce() -> io::Result<()>) -> io::Result<()> {
+        // restores the errors of an outer export, even if the export panics
+        struct Restore(Option<BTreeSet<String>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                ERRORS.with(|errors| *errors.borrow_mut() = previous);
+            }
+        }
+
+        let previous = ERRORS.with(|errors| errors.replace(Some(BTreeSet::new())));
+        let restore = Restore(previous);
+        let res = f();
+        let errors = ERRORS.with(|errors| errors.take()).unwrap_or_default();
+        drop(restore);
+        res?;
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                errors.into_iter().collect::<Vec<_>>().join("\n"),
+            ))
+        }
+    }
+
+    pub(crate) fn report_error(error: String) {
+        ERRORS.with(|errors| {
+            if let Some(errors) = errors.borrow_mut().as_mut() {
+                errors.insert(error);
+            }
+        });
+    }
+}
+
+/// Reports an error for large integers with `LargeIntegers::Deny`.
+pub(crate) fn deny_large_integer(rust_type: &str) {
+    Settings::report_error(format!(
+        "`{}` can't be represented exactly in Elm, use `LargeIntegers::Checked` or `LargeIntegers::String` for it",
+        rust_type
+    ));
+}
+
+/// Elm code for the large integer types that depends on the current `LargeIntegers`.
+pub(crate) mod large_integer {
+    use super::{deny_large_integer, LargeIntegers, Settings};
+
+    // Number.MAX_SAFE_INTEGER
+    const CHECK: &str = "abs elmRsInt <= 9007199254740991";
+
+    pub fn elm_type(rust_type: &str) -> String {
+        match Settings::current().large_integers {
+            LargeIntegers::Int | LargeIntegers::Checked => "Int",
+            LargeIntegers::String => "String",
+            LargeIntegers::Deny => {
+                deny_large_integer(rust_type);
+                "Int"
+            }
+        }
+        .to_string()
+    }
+
+    pub fn encoder_type(rust_type: &str) -> String {
+        match Settings::current().large_integers {
+            LargeIntegers::Int | LargeIntegers::Checked => "Json.Encode.int",
+            LargeIntegers::String => "Json.Encode.string",
+            LargeIntegers::Deny => {
+                deny_large_integer(rust_type);
+                "Json.Encode.int"
+            }
+        }
+        .to_string()
+    }
+
+    pub fn decoder_type(rust_type: &str) -> String {
+        match Settings::current().large_integers {
+            LargeIntegers::Int => "Json.Decode.int".to_string(),
+            LargeIntegers::Checked => format!(
+                r#"(Json.Decode.int |> Json.Decode.andThen (\elmRsInt -> if {} then Json.Decode.succeed elmRsInt else Json.Decode.fail ("Integer can't be represented exactly: " ++ String.fromInt elmRsInt)))"#,
+                CHECK
+            ),
+            LargeIntegers::String => "Json.Decode.string".to_string(),
+            LargeIntegers::Deny => {
+                deny_large_integer(rust_type);
+                "Json.Decode.int".to_string()
+            }
+        }
+    }
+
+    pub fn key_encoder(rust_type: &str) -> String {
+        match Settings::current().large_integers {
+            LargeIntegers::Int | LargeIntegers::Checked => "String.fromInt",
+            LargeIntegers::String => "identity",
+            LargeIntegers::Deny => {
+                deny_large_integer(rust_type);
+                "String.fromInt"
+            }
+        }
+        .to_string()
+    }
+
+    pub fn key_decoder(rust_type: &str) -> String {
+        match Settings::current().large_integers {
+            LargeIntegers::Int => "String.toInt".to_string(),
+            LargeIntegers::Checked => format!(
+                r#"(String.toInt >> Maybe.andThen (\elmRsInt -> if {} then Just elmRsInt else Nothing))"#,
+                CHECK
+            ),
+            LargeIntegers::String => "Just".to_string(),
+            LargeIntegers::Deny => {
+                deny_large_integer(rust_type);
+                "String.toInt".to_string()
+            }
+        }
+    }
+
+    pub fn string_key() -> bool {
+        Settings::current().large_integers == LargeIntegers::String
+    }
+
+    pub fn query_field_default() -> String {
+        match Settings::current().large_integers {
+            LargeIntegers::String => r#""0""#,
+            _ => "0",
+        }
+        .to_string()
+    }
+}
+
+/// Generates the Elm code for `T` with the `LargeIntegers` of `P`.
+/// Used by the derive macros for fields with the `#[elm(large_integers = "..")]` attribute.
+#[doc(hidden)]
+pub struct WithLargeIntegers<T: ?Sized, P>(PhantomData<P>, PhantomData<T>);
+
+#[doc(hidden)]
+pub mod large_integers {
+    use super::LargeIntegers;
+
+    pub trait Policy {
+        const LARGE_INTEGERS: LargeIntegers;
+    }
+
+    pub struct Int;
+    pub struct Checked;
+    pub struct String;
+    pub struct Deny;
+
+    impl Policy for Int {
+        const LARGE_INTEGERS: LargeIntegers = LargeIntegers::Int;
+    }
+
+    impl Policy for Checked {
+        const LARGE_INTEGERS: LargeIntegers = LargeIntegers::Checked;
+    }
+
+    impl Policy for String {
+        const LARGE_INTEGERS: LargeIntegers = LargeIntegers::String;
+    }
+
+    impl Policy for Deny {
+        const LARGE_INTEGERS: LargeIntegers = LargeIntegers::Deny;
+    }
+}
+
+fn with_large_integers<P: large_integers::Policy, R>(f: impl FnOnce() -> R) -> R {
+    Settings {
+        large_integers: P::LARGE_INTEGERS,
+        ..Settings::current()
+    }
+    .scope(f)
+}
+
+impl<T: Elm + ?Sized, P: large_integers::Policy> Elm for WithLargeIntegers<T, P> {
+    fn elm_type() -> String {
+        with_large_integers::<P, _>(T::elm_type)
+    }
+
+    fn elm_definition() -> Option<String> {
+        with_large_integers::<P, _>(T::elm_definition)
+    }
+}
+
+impl<T: ElmEncode + ?Sized, P: large_integers::Policy> ElmEncode for WithLargeIntegers<T, P> {
+    fn encoder_type() -> String {
+        with_large_integers::<P, _>(T::encoder_type)
+    }
+
+    fn encoder_definition() -> Option<String> {
+        with_large_integers::<P, _>(T::encoder_definition)
+    }
+}
+
+impl<T: ElmDecode + ?Sized, P: large_integers::Policy> ElmDecode for WithLargeIntegers<T, P> {
+    fn decoder_type() -> String {
+        with_large_integers::<P, _>(T::decoder_type)
+    }
+
+    fn decoder_definition() -> Option<String> {
+        with_large_integers::<P, _>(T::decoder_definition)
+    }
+}
+
+impl<T: ElmMapKey + ?Sized, P: large_integers::Policy> ElmMapKey for WithLargeIntegers<T, P> {
+    fn comparable() -> bool {
+        with_large_integers::<P, _>(T::comparable)
+    }
+
+    fn key_encoder() -> String {
+        with_large_integers::<P, _>(T::key_encoder)
+    }
+
+    fn key_decoder() -> String {
+        with_large_integers::<P, _>(T::key_decoder)
+    }
+
+    fn string_key() -> bool {
+        with_large_integers::<P, _>(T::string_key)
+    }
+}
+
+impl<T: ElmQueryField + ?Sized, P: large_integers::Policy> ElmQueryField
+    for WithLargeIntegers<T, P>
+{
+    fn query_field_encoder() -> String {
+        with_large_integers::<P, _>(T::query_field_encoder)
+    }
+
+    fn query_field_parser() -> String {
+        with_large_integers::<P, _>(T::query_field_parser)
+    }
+
+    fn query_field_default() -> Option<String> {
+        with_large_integers::<P, _>(T::query_field_default)
+    }
+
+    fn query_field_encoder_definition() -> Option<String> {
+        with_large_integers::<P, _>(T::query_field_encoder_definition)
+    }
+
+    fn query_field_optional() -> bool {
+        T::query_field_optional()
+    }
+
+    fn query_field_list() -> bool {
+        T::query_field_list()
+    }
+}
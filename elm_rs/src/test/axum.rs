@@ -0,0 +1,290 @@
+use crate::{
+    axum::{delete, get, get_named, post, put, ElmRouter},
+    Elm, ElmDecode, ElmEncode, ElmForm, ElmQuery, ElmQueryField,
+};
+use axum::{
+    extract::{Form, Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
+
+#[derive(Serialize, Deserialize, Elm, ElmEncode, ElmDecode)]
+struct Drawing {
+    id: u32,
+    title: String,
+}
+
+#[derive(Serialize, Deserialize, Elm, ElmQuery)]
+struct Search {
+    title: Option<String>,
+    page: u32,
+}
+
+#[derive(Serialize, Deserialize, Elm, ElmDecode)]
+struct ApiError {
+    message: String,
+}
+
+#[derive(Serialize, Deserialize, Elm, ElmForm)]
+struct Rename {
+    title: String,
+}
+
+#[derive(Serialize, Deserialize, Elm, ElmQuery)]
+struct CommentPath {
+    drawing_id: u32,
+    #[serde(rename = "comment")]
+    comment_id: u32,
+}
+
+#[derive(Clone)]
+struct AppState;
+
+async fn get_drawing(Path(id): Path<u32>) -> Json<Drawing> {
+    Json(Drawing {
+        id,
+        title: String::new(),
+    })
+}
+
+async fn list_drawings(Query(_search): Query<Search>) -> Json<Vec<Drawing>> {
+    Json(Vec::new())
+}
+
+async fn create_drawing(
+    State(_state): State<AppState>,
+    Json(drawing): Json<Drawing>,
+) -> Result<Json<Drawing>, Json<ApiError>> {
+    Ok(Json(drawing))
+}
+
+async fn rename_drawing(
+    Path((_id, _version)): Path<(u32, String)>,
+    Form(_rename): Form<Rename>,
+) -> StatusCode {
+    StatusCode::NO_CONTENT
+}
+
+async fn delete_comment(Path(_path): Path<CommentPath>) -> Result<(), StatusCode> {
+    Ok(())
+}
+
+fn app() -> ElmRouter<AppState> {
+    ElmRouter::new()
+        .route("/drawings", get(list_drawings).post(create_drawing))
+        .route("/drawings/{id}", get(get_drawing))
+        .route("/drawings/{id}/{version}", put(rename_drawing))
+        .merge(ElmRouter::new().route(
+            "/drawings/{drawing_id}/comments/{comment}",
+            delete(delete_comment),
+        ))
+}
+
+fn deps() -> String {
+    [
+        Drawing::elm_definition().unwrap(),
+        Drawing::encoder_definition().unwrap(),
+        Drawing::decoder_definition().unwrap(),
+        Search::elm_definition().unwrap(),
+        Search::elm_query(),
+        ApiError::elm_definition().unwrap(),
+        ApiError::decoder_definition().unwrap(),
+        Rename::elm_definition().unwrap(),
+        Rename::elm_form(),
+        CommentPath::elm_definition().unwrap(),
+    ]
+    .join("\n\n")
+}
+
+fn export_routers(router: ElmRouter<AppState>) -> String {
+    let mut target = vec![];
+    crate::export!("Bindings", &mut target, {
+        routers: [router],
+    })
+    .unwrap();
+    String::from_utf8(target).unwrap()
+}
+
+#[test]
+fn axum_router() {
+    let _router: axum::Router<AppState> = app().into();
+    let module = export_routers(app());
+    assert!(module.contains(
+        "listDrawings : Search -> (Result Http.Error (List (Drawing)) -> msg) -> Cmd msg\n\
+         listDrawings elmRsQuery elmRsToMsg =\n"
+    ));
+    assert!(module
+        .contains(", url = Url.Builder.absolute [ \"drawings\" ] (urlEncodeSearch elmRsQuery)"));
+    assert!(module.contains(
+        "createDrawing : Drawing -> (Result (EndpointError ApiError) Drawing -> msg) -> Cmd msg"
+    ));
+    assert!(module.contains(", body = Http.jsonBody (drawingEncoder elmRsBody)"));
+    assert!(module.contains("getDrawing : Int -> (Result Http.Error Drawing -> msg) -> Cmd msg"));
+    assert!(module.contains(
+        ", url = Url.Builder.absolute [ \"drawings\", Url.percentEncode (String.fromInt elmRsPath0) ] []"
+    ));
+    assert!(module.contains(
+        "renameDrawing : Int -> String -> Rename -> (Result Http.Error () -> msg) -> Cmd msg"
+    ));
+    assert!(module.contains(", body = urlEncodedBodyRename elmRsBody"));
+    assert!(module.contains(", expect = Http.expectWhatever elmRsToMsg"));
+    assert!(
+        module.contains("deleteComment : CommentPath -> (Result Http.Error () -> msg) -> Cmd msg")
+    );
+    assert!(module.contains(
+        "Url.percentEncode (String.fromInt elmRsPath0.drawingId), \"comments\", Url.percentEncode (String.fromInt elmRsPath0.commentId)"
+    ));
+
+    crate::Settings::__export(|| {
+        let endpoints = app().elm_endpoints().join("\n\n");
+        super::test_endpoint(
+            &deps(),
+            &endpoints,
+            "( listDrawings, createDrawing, ( getDrawing, renameDrawing, deleteComment ) )",
+        );
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[derive(Serialize, Deserialize, Elm, ElmQueryField)]
+enum Format {
+    Png,
+    Svg,
+}
+
+#[test]
+fn axum_router_path_values() {
+    async fn list_by_format(Path(_format): Path<Format>) -> StatusCode {
+        StatusCode::OK
+    }
+    async fn export_drawing(Path(_id): Path<NonZeroU32>) -> StatusCode {
+        StatusCode::OK
+    }
+    async fn export_drawing_as(Path((_id, _format)): Path<(NonZeroU32, Format)>) -> StatusCode {
+        StatusCode::OK
+    }
+
+    let router = || {
+        ElmRouter::<()>::new()
+            .route("/formats/{format}", get(list_by_format))
+            .route("/drawings/{id}/export", get(export_drawing))
+            .route("/drawings/{id}/png", get_named("exportPng", export_drawing))
+            .route("/drawings/{id}/export/{format}", get(export_drawing_as))
+    };
+    let mut target = vec![];
+    crate::export!("Bindings", &mut target, {
+        routers: [router()],
+    })
+    .unwrap();
+    let module = String::from_utf8(target).unwrap();
+    assert!(module.contains("listByFormat : Format -> (Result Http.Error () -> msg) -> Cmd msg"));
+    assert!(module.contains(
+        ", url = Url.Builder.absolute [ \"formats\", Url.percentEncode (queryFieldEncoderFormat elmRsPath0) ] []"
+    ));
+    assert!(module.contains("exportDrawing : Int -> (Result Http.Error () -> msg) -> Cmd msg"));
+    assert!(module.contains("exportPng : Int -> (Result Http.Error () -> msg) -> Cmd msg"));
+    assert!(module
+        .contains("exportDrawingAs : Int -> Format -> (Result Http.Error () -> msg) -> Cmd msg"));
+
+    crate::Settings::__export(|| {
+        let deps = [
+            Format::elm_definition().unwrap(),
+            Format::query_field_encoder_definition().unwrap(),
+        ]
+        .join("\n\n");
+        let endpoints = router().elm_endpoints().join("\n\n");
+        super::test_endpoint(
+            &deps,
+            &endpoints,
+            "( listByFormat, exportDrawing, ( exportPng, exportDrawingAs ) )",
+        );
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn axum_router_text_and_nest() {
+    async fn get_title(Path(_id): Path<u32>) -> String {
+        String::new()
+    }
+    async fn get_version() -> &'static str {
+        "1"
+    }
+    async fn get_description(Path(_id): Path<u32>) -> Result<String, Json<ApiError>> {
+        Ok(String::new())
+    }
+
+    let router = || {
+        ElmRouter::<()>::new().nest(
+            "/api",
+            ElmRouter::new()
+                .route("/", get(get_version))
+                .route("/drawings/{id}/title", get(get_title))
+                .route("/drawings/{id}/description", get(get_description)),
+        )
+    };
+    let _router: axum::Router = router().into_router();
+    let mut target = vec![];
+    crate::export!("Bindings", &mut target, {
+        routers: [router()],
+    })
+    .unwrap();
+    let module = String::from_utf8(target).unwrap();
+    assert!(module.contains("getVersion : (Result Http.Error String -> msg) -> Cmd msg"));
+    assert!(module.contains(", url = Url.Builder.absolute [ \"api\" ] []"));
+    assert!(module.contains(", expect = Http.expectString elmRsToMsg"));
+    assert!(module.contains(
+        ", url = Url.Builder.absolute [ \"api\", \"drawings\", Url.percentEncode (String.fromInt elmRsPath0), \"title\" ] []"
+    ));
+    assert!(module.contains(
+        "getDescription : Int -> (Result (EndpointError ApiError) String -> msg) -> Cmd msg"
+    ));
+    assert!(module.contains(", expect = expectEndpoint elmRsToMsg (apiErrorDecoder) Ok"));
+
+    crate::Settings::__export(|| {
+        let deps = [
+            ApiError::elm_definition().unwrap(),
+            ApiError::decoder_definition().unwrap(),
+        ]
+        .join("\n\n");
+        let endpoints = router().elm_endpoints().join("\n\n");
+        super::test_endpoint(
+            &deps,
+            &endpoints,
+            "( getVersion, getTitle, getDescription )",
+        );
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn axum_router_errors() {
+    async fn list_comments(Path(_id): Path<u32>) {}
+    async fn list_versions(Path(_id): Path<Option<u32>>) {}
+
+    let router = ElmRouter::<()>::new()
+        .route("/drawings/{id}/{version}", get(get_drawing))
+        .route("/files/{*rest}", post(|| async {}))
+        .route("/comments", get(list_comments))
+        .route("/versions/{id}", get(list_versions))
+        .route("/drafts", get_named("List drafts", || async {}));
+    let mut target = vec![];
+    let err = crate::export!("Bindings", &mut target, {
+        routers: [router],
+    })
+    .unwrap_err()
+    .to_string();
+    assert!(
+        err.contains("the path parameter `version` of `/drawings/{id}/{version}` is not extracted")
+    );
+    assert!(err.contains("the wildcard `{*rest}` in `/files/{*rest}` is not supported"));
+    assert!(err.contains("needs to be a function to name the Elm function"));
+    assert!(err.contains("`List drafts` for `GET /drafts` is not a valid name for an Elm function"));
+    assert!(err.contains("extracts more path parameters than `/comments` has"));
+    assert!(err.contains("`Maybe (Int)` needs to have a single value to be a path parameter"));
+}
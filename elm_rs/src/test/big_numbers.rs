@@ -0,0 +1,118 @@
+use crate::{Elm, ElmDecode, ElmEncode};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn rust_decimal() {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+    struct Price {
+        amount: Decimal,
+    }
+
+    super::test_json_with_deps(
+        Price {
+            amount: Decimal::from_str("-1234.5").unwrap(),
+        },
+        &format!(
+            "{}\n\n{}\n\n{}\n\n",
+            Decimal::elm_definition().unwrap(),
+            Decimal::encoder_definition().unwrap(),
+            Decimal::decoder_definition().unwrap(),
+        ),
+    );
+}
+
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn bigdecimal() {
+    use bigdecimal::BigDecimal;
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+    struct Price {
+        amount: BigDecimal,
+    }
+
+    super::test_json_with_deps(
+        Price {
+            amount: BigDecimal::from_str("12345678901234567890.123456789").unwrap(),
+        },
+        &format!(
+            "{}\n\n{}\n\n{}\n\n",
+            BigDecimal::elm_definition().unwrap(),
+            BigDecimal::encoder_definition().unwrap(),
+            BigDecimal::decoder_definition().unwrap(),
+        ),
+    );
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn num_bigint() {
+    use num_bigint::{BigInt, BigUint};
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+    struct BigInts {
+        zero: BigInt,
+        negative: BigInt,
+        positive: BigInt,
+        unsigned: BigUint,
+    }
+
+    super::test_json_with_deps(
+        BigInts {
+            zero: BigInt::from(0),
+            negative: BigInt::from_str("-123456789012345678901234567890").unwrap(),
+            positive: BigInt::from(u64::MAX),
+            unsigned: BigUint::from_str("98765432109876543210").unwrap(),
+        },
+        &format!(
+            "{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n{}\n\n",
+            BigInt::elm_definition().unwrap(),
+            BigInt::encoder_definition().unwrap(),
+            BigInt::decoder_definition().unwrap(),
+            BigUint::elm_definition().unwrap(),
+            BigUint::encoder_definition().unwrap(),
+            BigUint::decoder_definition().unwrap(),
+        ),
+    );
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn num_bigint_query() {
+    use crate::ElmQuery;
+    use num_bigint::{BigInt, BigUint};
+    use std::str::FromStr;
+
+    #[derive(Serialize, Elm, ElmQuery)]
+    struct BigQuery {
+        #[serde(with = "super::display_from_str")]
+        negative: BigInt,
+        #[serde(with = "super::display_from_str")]
+        unsigned: BigUint,
+    }
+
+    let query = BigQuery {
+        negative: BigInt::from_str("-123456789012345678901234567890").unwrap(),
+        unsigned: BigUint::from_str("98765432109876543210").unwrap(),
+    };
+    let expected = serde_qs::to_string(&query).unwrap();
+    assert_eq!(
+        expected,
+        "negative=-123456789012345678901234567890&unsigned=98765432109876543210"
+    );
+    super::test_query_with_elm_value::<BigQuery>(
+        r#"{ negative = BigInt "-123456789012345678901234567890", unsigned = BigUint "98765432109876543210" }"#,
+        &format!(
+            "{}\n\n{}",
+            BigInt::elm_definition().unwrap(),
+            BigUint::elm_definition().unwrap(),
+        ),
+        &format!("?{expected}"),
+    );
+}
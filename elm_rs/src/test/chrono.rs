@@ -0,0 +1,78 @@
+use crate::{Elm, ElmDecode, ElmEncode, ElmQuery};
+use chrono::{
+    DateTime, Duration, FixedOffset, Month, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+    Weekday,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Chrono {
+    naive_time: NaiveTime,
+    naive_date: NaiveDate,
+    naive_date_time: NaiveDateTime,
+    date_time: DateTime<Utc>,
+    duration: Duration,
+    #[serde(with = "super::display_from_str")]
+    fixed_offset: FixedOffset,
+    weekday: Weekday,
+    month: Month,
+}
+
+#[derive(Deserialize, Serialize, Elm, ElmEncode, ElmDecode, ElmQuery)]
+struct WeekdayQuery {
+    weekday: Weekday,
+}
+
+#[derive(Deserialize, Serialize, Elm, ElmEncode, ElmDecode, ElmQuery)]
+struct MonthQuery {
+    month: Month,
+}
+
+#[test]
+fn chrono() {
+    let date_time = Utc.with_ymd_and_hms(2022, 8, 4, 12, 34, 56).unwrap();
+    let deps = [
+        Duration::elm_definition(),
+        Duration::encoder_definition(),
+        Duration::decoder_definition(),
+        Weekday::elm_definition(),
+        Weekday::encoder_definition(),
+        Weekday::decoder_definition(),
+        Month::elm_definition(),
+        Month::encoder_definition(),
+        Month::decoder_definition(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("\n\n");
+    super::test_json_with_deps(
+        Chrono {
+            naive_time: date_time.time(),
+            naive_date: date_time.date_naive(),
+            naive_date_time: date_time.naive_utc(),
+            date_time,
+            duration: Duration::new(-12, 345).unwrap(),
+            fixed_offset: FixedOffset::east_opt(3 * 3600 + 30 * 60).unwrap(),
+            weekday: Weekday::Sun,
+            month: Month::December,
+        },
+        &deps,
+    );
+}
+
+#[test]
+fn chrono_query() {
+    super::test_query::<_, Weekday>(
+        WeekdayQuery {
+            weekday: Weekday::Wed,
+        },
+        "?weekday=Wed",
+    );
+    super::test_query::<_, Month>(
+        MonthQuery {
+            month: Month::February,
+        },
+        "?month=February",
+    );
+}
@@ -0,0 +1,72 @@
+use crate::{Elm, ElmDecode, ElmEncode};
+use arrayvec::{ArrayString, ArrayVec};
+use indexmap::{IndexMap, IndexSet};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use smallvec::SmallVec;
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Collections {
+    index_set: IndexSet<u32>,
+    small_vec: SmallVec<[u32; 2]>,
+    array_vec: ArrayVec<u32, 4>,
+    array_string: ArrayString<8>,
+    bytes: bytes::Bytes,
+    byte_buf: ByteBuf,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Ordered {
+    map: IndexMap<String, u32>,
+    array_string_keys: IndexMap<ArrayString<8>, u32>,
+}
+
+fn ordered() -> Ordered {
+    Ordered {
+        map: IndexMap::from([
+            ("c".to_string(), 0),
+            ("a".to_string(), 1),
+            ("b".to_string(), 2),
+        ]),
+        array_string_keys: IndexMap::from([
+            (ArrayString::from("z").unwrap(), 0),
+            (ArrayString::from("y").unwrap(), 1),
+        ]),
+    }
+}
+
+#[test]
+fn collections() {
+    super::test_json(Collections {
+        index_set: IndexSet::from([3, 1, 2]),
+        small_vec: SmallVec::from_vec(vec![1, 2, 3]),
+        array_vec: ArrayVec::from([1, 2, 3, 4]),
+        array_string: ArrayString::from("string").unwrap(),
+        bytes: bytes::Bytes::from_static(b"bytes"),
+        byte_buf: ByteBuf::from(vec![0, 127, 255]),
+    });
+}
+
+#[test]
+fn index_map_dict() {
+    assert_eq!(IndexMap::<String, u32>::elm_type(), "Dict (String) (Int)");
+    let ordered = super::test_json_without_eq(&ordered(), "");
+    assert_eq!(ordered.map.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+}
+
+#[test]
+fn index_map_ordered() {
+    let settings = crate::Settings {
+        ordered_index_maps: true,
+        ..Default::default()
+    };
+    settings.scope(|| {
+        assert_eq!(IndexMap::<String, u32>::elm_type(), "List ( String, Int )");
+        let ordered = super::test_json_without_eq(&ordered(), "");
+        assert_eq!(ordered.map.keys().collect::<Vec<_>>(), ["c", "a", "b"]);
+        assert_eq!(
+            ordered.array_string_keys.keys().collect::<Vec<_>>(),
+            ["z", "y"]
+        );
+    });
+}
@@ -0,0 +1,126 @@
+use crate::{Elm, ElmDecode, ElmEncode, ElmPath, ElmQuery, Endpoint};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Elm, ElmEncode, ElmDecode)]
+struct Drawing {
+    id: i32,
+    title: String,
+}
+
+#[derive(Serialize, Elm, ElmQuery)]
+struct Options {
+    thumbnail: bool,
+}
+
+#[derive(Serialize, ElmPath)]
+#[elm(path = "/drawings/{id}")]
+struct SingleDrawing {
+    id: i32,
+    #[elm(query)]
+    options: Options,
+}
+
+#[allow(dead_code)]
+#[derive(ElmPath)]
+#[elm(path = "/drawings")]
+struct AllDrawings {}
+
+#[derive(Serialize, Deserialize, Elm, ElmDecode)]
+struct ApiError {
+    message: String,
+}
+
+struct GetDrawing;
+
+impl Endpoint for GetDrawing {
+    const NAME: &'static str = "getDrawing";
+    const METHOD: &'static str = "GET";
+    type Path = SingleDrawing;
+    type Body = ();
+    type Response = Drawing;
+    type Error = ();
+}
+
+struct CreateDrawing;
+
+impl Endpoint for CreateDrawing {
+    const NAME: &'static str = "createDrawing";
+    const METHOD: &'static str = "POST";
+    type Path = AllDrawings;
+    type Body = Drawing;
+    type Response = Vec<Drawing>;
+    type Error = ApiError;
+}
+
+struct DeleteDrawing;
+
+impl Endpoint for DeleteDrawing {
+    const NAME: &'static str = "deleteDrawing";
+    const METHOD: &'static str = "DELETE";
+    type Path = SingleDrawing;
+    type Body = ();
+    type Response = ();
+    type Error = ApiError;
+}
+
+fn deps() -> String {
+    [
+        Drawing::elm_definition().unwrap(),
+        Drawing::encoder_definition().unwrap(),
+        Drawing::decoder_definition().unwrap(),
+        ApiError::elm_definition().unwrap(),
+        ApiError::decoder_definition().unwrap(),
+        Options::elm_definition().unwrap(),
+        Options::elm_query(),
+        SingleDrawing::elm_path(),
+        AllDrawings::elm_path(),
+    ]
+    .join("\n\n")
+}
+
+#[test]
+fn endpoint_get() {
+    let endpoint = GetDrawing::elm_endpoint();
+    assert!(endpoint.starts_with(
+        "getDrawing : { id : Int, options : Options } -> (Result Http.Error Drawing -> msg) -> Cmd msg\n\
+         getDrawing elmRsPath elmRsToMsg =\n"
+    ));
+    assert!(endpoint.contains(r#"{ method = "GET""#));
+    assert!(endpoint.contains(", url = singleDrawingPath elmRsPath"));
+    assert!(endpoint.contains(", body = Http.emptyBody"));
+    assert!(endpoint.contains(", expect = Http.expectJson elmRsToMsg (drawingDecoder)"));
+    super::test_endpoint(&deps(), &GetDrawing::elm_endpoint(), GetDrawing::NAME);
+}
+
+#[test]
+fn endpoint_body_and_error() {
+    let endpoint = CreateDrawing::elm_endpoint();
+    assert!(endpoint.starts_with(
+        "createDrawing : Drawing -> (Result (EndpointError ApiError) (List (Drawing)) -> msg) -> Cmd msg\n\
+         createDrawing elmRsBody elmRsToMsg =\n"
+    ));
+    assert!(endpoint.contains(", url = allDrawingsPath {}"));
+    assert!(endpoint.contains(", body = Http.jsonBody (drawingEncoder elmRsBody)"));
+    assert!(endpoint.contains(", expect = expectEndpoint elmRsToMsg (apiErrorDecoder)"));
+    super::test_endpoint(&deps(), &CreateDrawing::elm_endpoint(), CreateDrawing::NAME);
+}
+
+#[test]
+fn endpoint_without_response() {
+    let endpoint = DeleteDrawing::elm_endpoint();
+    assert!(endpoint.starts_with(
+        "deleteDrawing : { id : Int, options : Options } -> (Result (EndpointError ApiError) () -> msg) -> Cmd msg\n"
+    ));
+    assert!(endpoint
+        .contains(r#", expect = expectEndpoint elmRsToMsg (apiErrorDecoder) (\_ -> Ok ())"#));
+
+    let mut target = vec![];
+    crate::export!("Bindings", &mut target, {
+        endpoints: [GetDrawing, DeleteDrawing],
+    })
+    .unwrap();
+    let module = String::from_utf8(target).unwrap();
+    assert_eq!(module.matches("type EndpointError e").count(), 1);
+    assert!(module.contains("getDrawing : "));
+    super::test_endpoint(&deps(), &DeleteDrawing::elm_endpoint(), DeleteDrawing::NAME);
+}
@@ -90,3 +90,49 @@ fn skip() {
         not_skipped: 0,
     });
 }
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+#[serde(deny_unknown_fields)]
+struct DenyUnknownFields {
+    field: u8,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+#[serde(tag = "t", deny_unknown_fields)]
+enum DenyUnknownFieldsInternal {
+    Struct { field: u8 },
+}
+
+#[test]
+fn deny_unknown_fields() {
+    super::test_json(DenyUnknownFields { field: 0 });
+    let err =
+        super::test_decode_error::<DenyUnknownFields>("{\\\"field\\\":0,\\\"unknown\\\":0}", "");
+    assert!(err.contains("Unexpected field unknown"));
+}
+
+#[test]
+fn deny_unknown_fields_internal() {
+    super::test_json(DenyUnknownFieldsInternal::Struct { field: 0 });
+    let err = super::test_decode_error::<DenyUnknownFieldsInternal>(
+        "{\\\"t\\\":\\\"Struct\\\",\\\"field\\\":0,\\\"unknown\\\":0}",
+        "",
+    );
+    assert!(err.contains("Unexpected field unknown"));
+}
+
+#[test]
+fn deny_unknown_fields_setting() {
+    let settings = crate::Settings {
+        deny_unknown_fields: true,
+        ..Default::default()
+    };
+    settings.scope(|| {
+        super::test_json(Skip {
+            skipped: 0,
+            not_skipped: 0,
+        });
+        let err = super::test_decode_error::<Skip>("{\\\"skipped\\\":0,\\\"not_skipped\\\":0}", "");
+        assert!(err.contains("Unexpected field skipped"));
+    });
+}
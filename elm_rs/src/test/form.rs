@@ -0,0 +1,104 @@
+use crate::{Elm, ElmForm, ElmQuery, File};
+use serde::Serialize;
+
+#[derive(Serialize, Elm, ElmForm)]
+struct Login {
+    username: String,
+    password: String,
+    remember: bool,
+    #[elm(query_style = "comma")]
+    scopes: Vec<String>,
+    note: Option<String>,
+}
+
+#[test]
+fn form_url_encoded() {
+    assert!(!Login::form_has_files());
+    let form = Login::elm_form();
+    assert!(form.contains("urlEncodedBodyLogin : Login -> Http.Body"));
+    assert!(form.contains(r#"[ Http.stringPart "username" (identity struct.username) ]"#));
+    assert!(form.contains(
+        r#"Maybe.withDefault [] (Maybe.map (List.singleton << Http.stringPart "note" << identity) struct.note)"#
+    ));
+    super::test_form::<Login>(
+        r#"always "ok" [ urlEncodedBodyLogin { username = "user", password = "pass", remember = True, scopes = [ "a", "b" ], note = Nothing }, multipartBodyLogin { username = "user", password = "pass", remember = True, scopes = [], note = Just "note" } ]"#,
+        "ok",
+    );
+}
+
+#[derive(Serialize, Elm, ElmForm)]
+struct Contact {
+    name: String,
+    age: u32,
+    subscribed: bool,
+    note: Option<String>,
+    email: Option<String>,
+}
+
+#[test]
+fn form_url_encoded_body() {
+    let contact = Contact {
+        name: "a b&c=d".to_string(),
+        age: 30,
+        subscribed: true,
+        note: None,
+        email: Some("a@b.c".to_string()),
+    };
+    // Url.Builder percent-encodes spaces instead of using `+`
+    let expected = serde_urlencoded::to_string(&contact)
+        .unwrap()
+        .replace('+', "%20");
+    assert_eq!(
+        expected,
+        "name=a%20b%26c%3Dd&age=30&subscribed=true&email=a%40b.c"
+    );
+    super::test_url_encoded_form::<Contact>(
+        r#"{ name = "a b&c=d", age = 30, subscribed = True, note = Nothing, email = Just "a@b.c" }"#,
+        &expected,
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Elm, ElmForm)]
+struct Upload {
+    title: String,
+    file: File,
+    thumbnail: Option<File>,
+    attachments: Vec<File>,
+}
+
+#[test]
+fn form_multipart_files() {
+    assert!(Upload::form_has_files());
+    let form = Upload::elm_form();
+    assert!(!form.contains("urlEncodedBodyUpload"));
+    assert!(form.contains(r#"[ Http.filePart "file" struct.file ]"#));
+    assert!(form.contains(r#"List.map (Http.filePart "attachments") struct.attachments"#));
+
+    let mut target = vec![];
+    crate::export!("Bindings", &mut target, {
+        forms: [Upload],
+    })
+    .unwrap();
+    assert!(String::from_utf8(target)
+        .unwrap()
+        .contains("\nimport File\n"));
+    super::test_form::<Upload>(r#""ok""#, "ok");
+}
+
+#[allow(dead_code)]
+#[derive(Elm, ElmQuery)]
+struct FileQuery {
+    file: File,
+}
+
+#[test]
+fn form_file_in_query() {
+    let err = crate::export!("Bindings", &mut vec![], {
+        queries: [FileQuery],
+    })
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("the file field `file` can only be used in multipart forms"));
+}
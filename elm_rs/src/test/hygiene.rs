@@ -13,12 +13,31 @@ struct Drawing {
     filetype: Filetype,
 }
 
+#[derive(crate::elm_rs::ElmPath)]
+#[elm(path = "/drawings/{id}")]
+struct DrawingPath {
+    id: u32,
+}
+
+struct GetDrawing;
+
+impl crate::elm_rs::Endpoint for GetDrawing {
+    const NAME: &'static str = "getDrawing";
+    const METHOD: &'static str = "GET";
+    type Path = DrawingPath;
+    type Body = ();
+    type Response = Drawing;
+    type Error = ();
+}
+
 #[test]
 fn hygiene() {
     let mut target = ::std::vec![];
     crate::elm_rs::export!("Bindings", &mut target, {
         encoders: [Drawing, Filetype],
         decoders: [Drawing, Filetype],
+        paths: [DrawingPath],
+        endpoints: [GetDrawing],
     })
     .unwrap();
 }
@@ -0,0 +1,30 @@
+use crate::{Elm, ElmDecode, ElmEncode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, value::RawValue, Map, Value};
+
+#[derive(Debug, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Payload {
+    value: Value,
+    map: Map<String, Value>,
+    raw: Box<RawValue>,
+}
+
+#[test]
+fn json_value() {
+    let mut map = Map::new();
+    map.insert("key".to_string(), json!([1, "2", null]));
+    let payload = super::test_json_without_eq(
+        &Payload {
+            value: json!({ "nested": { "a": 1.5, "b": [true, false] } }),
+            map,
+            raw: RawValue::from_string("{\"raw\":[1,2,3]}".to_string()).unwrap(),
+        },
+        "",
+    );
+    assert_eq!(payload.value["nested"]["a"], 1.5);
+    assert_eq!(payload.map["key"], json!([1, "2", null]));
+    assert_eq!(
+        serde_json::from_str::<Value>(payload.raw.get()).unwrap(),
+        json!({ "raw": [1, 2, 3] })
+    );
+}
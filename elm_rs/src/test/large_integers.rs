@@ -0,0 +1,118 @@
+use crate::{Elm, ElmDecode, ElmEncode, LargeIntegers, Settings};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Snowflake(
+    #[elm(large_integers = "string")]
+    #[serde(with = "super::display_from_str")]
+    u64,
+);
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Message {
+    id: Snowflake,
+    #[elm(large_integers = "string")]
+    #[serde(with = "super::display_from_str")]
+    author: i128,
+    #[elm(large_integers = "checked")]
+    likes: u64,
+    #[elm(large_integers = "string")]
+    reactions: HashMap<u64, u8>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Counter {
+    count: u32,
+    total: u64,
+}
+
+#[test]
+fn large_integers_field() {
+    assert_eq!(
+        Snowflake::elm_definition().unwrap(),
+        "type Snowflake\n    = Snowflake (String)\n"
+    );
+    super::test_json_with_deps(
+        Message {
+            id: Snowflake(u64::MAX),
+            author: i128::MIN,
+            likes: 1,
+            reactions: HashMap::from([(u64::MAX, 1)]),
+        },
+        &format!(
+            "{}\n\n{}\n\n{}\n\n",
+            Snowflake::elm_definition().unwrap(),
+            Snowflake::encoder_definition().unwrap(),
+            Snowflake::decoder_definition().unwrap(),
+        ),
+    );
+}
+
+#[test]
+fn large_integers_setting() {
+    let settings = Settings {
+        large_integers: LargeIntegers::String,
+        ..Default::default()
+    };
+    settings.scope(|| {
+        assert_eq!(u64::elm_type(), "String");
+        assert_eq!(i64::decoder_type(), "Json.Decode.string");
+        // the field attribute takes priority
+        assert_eq!(
+            Message::elm_definition().unwrap(),
+            Settings::default().scope(|| Message::elm_definition().unwrap())
+        );
+    });
+}
+
+#[test]
+fn large_integers_checked() {
+    let settings = Settings {
+        large_integers: LargeIntegers::Checked,
+        ..Default::default()
+    };
+    settings.scope(|| {
+        super::test_json(Counter {
+            count: 0,
+            total: 9007199254740991,
+        });
+        let err = super::test_decode_error::<u64>("9007199254740993", "");
+        assert!(err.contains("Integer can't be represented exactly"));
+    });
+}
+
+#[test]
+fn large_integers_deny() {
+    let settings = Settings {
+        large_integers: LargeIntegers::Deny,
+        ..Default::default()
+    };
+    let err = crate::export!("Bindings", &mut vec![], {
+        settings: settings.clone(),
+        encoders: [Counter],
+    })
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("`u64` can't be represented exactly in Elm"));
+    crate::export!("Bindings", &mut vec![], {
+        settings: settings,
+        encoders: [Snowflake],
+    })
+    .unwrap();
+}
+
+#[test]
+fn large_integers_deny_outside_export() {
+    let settings = Settings {
+        large_integers: LargeIntegers::Deny,
+        ..Default::default()
+    };
+    // errors are only collected during exports
+    settings.scope(<Counter as Elm>::elm_definition);
+    crate::export!("Bindings", &mut vec![], {
+        encoders: [Counter],
+    })
+    .unwrap();
+}
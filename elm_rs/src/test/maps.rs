@@ -0,0 +1,108 @@
+use crate::{Elm, ElmDecode, ElmEncode, ElmMapKey};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Deserialize,
+    Serialize,
+    Elm,
+    ElmEncode,
+    ElmDecode,
+    ElmMapKey,
+)]
+#[serde(rename_all = "lowercase")]
+enum Filetype {
+    Jpeg,
+    Png,
+}
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Deserialize,
+    Serialize,
+    Elm,
+    ElmEncode,
+    ElmDecode,
+    ElmMapKey,
+)]
+struct Key(u32);
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Maps {
+    int: HashMap<u32, u8>,
+    char: BTreeMap<char, u8>,
+    enum_key: HashMap<Filetype, u8>,
+    newtype: BTreeMap<Key, u8>,
+    nested: HashMap<Filetype, HashMap<u32, u8>>,
+}
+
+#[test]
+fn maps() {
+    super::test_json_with_deps(
+        Maps {
+            int: HashMap::from([(1, 2), (3, 4)]),
+            char: BTreeMap::from([('a', 1), ('b', 2)]),
+            enum_key: HashMap::from([(Filetype::Jpeg, 1), (Filetype::Png, 2)]),
+            newtype: BTreeMap::from([(Key(1), 2)]),
+            nested: HashMap::from([(Filetype::Png, HashMap::from([(1, 2)]))]),
+        },
+        &format!(
+            "\
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+",
+            Filetype::elm_definition().unwrap(),
+            Filetype::encoder_definition().unwrap(),
+            Filetype::decoder_definition().unwrap(),
+            Key::elm_definition().unwrap(),
+            Key::encoder_definition().unwrap(),
+            Key::decoder_definition().unwrap(),
+        ),
+    );
+}
+
+#[test]
+fn map_types() {
+    assert_eq!(HashMap::<String, u8>::elm_type(), "Dict (String) (Int)");
+    assert_eq!(HashMap::<u32, u8>::elm_type(), "Dict (Int) (Int)");
+    assert_eq!(
+        HashMap::<Filetype, u8>::elm_type(),
+        "List ( Filetype, Int )"
+    );
+    // only string keys are decoded without parsing them
+    assert!(HashMap::<String, u8>::decoder_type().starts_with("Json.Decode.dict"));
+    assert!(HashMap::<u32, u8>::decoder_type().starts_with("(Json.Decode.keyValuePairs"));
+}
+
+#[test]
+fn map_invalid_key() {
+    super::test_decode_error::<HashMap<Filetype, u8>>(
+        "{\\\"gif\\\":0}",
+        &format!(
+            "{}\n\n{}",
+            Filetype::elm_definition().unwrap(),
+            Filetype::decoder_definition().unwrap(),
+        ),
+    );
+}
@@ -1,4 +1,6 @@
-use crate::{Elm, ElmDecode, ElmEncode, ElmQuery, ElmQueryField};
+use crate::{
+    Elm, ElmDecode, ElmEncode, ElmForm, ElmPath, ElmQuery, ElmQueryField, ElmQueryParser, ElmRoute,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     fmt::Debug,
@@ -6,26 +8,88 @@ use std::{
     process::{Command, Stdio},
 };
 
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(any(
+    feature = "rust_decimal",
+    feature = "bigdecimal",
+    feature = "num-bigint"
+))]
+mod big_numbers;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(all(
+    feature = "indexmap",
+    feature = "smallvec",
+    feature = "arrayvec",
+    feature = "bytes",
+    feature = "serde_bytes"
+))]
+mod collections;
 mod complex;
+mod endpoint;
 mod enums_adjacent;
 mod enums_external;
 mod enums_internal;
 mod enums_untagged;
 mod etc_serde;
+mod form;
 mod hygiene;
+#[cfg(feature = "serde_json")]
+mod json_value;
+mod large_integers;
+mod maps;
 mod nested;
+mod path;
+mod phantom;
+#[cfg(all(feature = "time", feature = "chrono"))]
+mod posix;
 mod query;
 mod regression;
+mod route;
 mod structs;
 mod structs_serde;
+#[cfg(feature = "time")]
+mod time;
+mod tuples;
 mod types;
+#[cfg(all(
+    feature = "url",
+    feature = "semver",
+    feature = "mime",
+    feature = "http"
+))]
+mod web;
+
+// serializes the value as a string, like serde_with::DisplayFromStr
+mod display_from_str {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::{fmt::Display, str::FromStr};
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        T::Err: Display,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
 
 fn test_json<T: Elm + ElmEncode + ElmDecode + Serialize + DeserializeOwned + PartialEq + Debug>(
     t: T,
 ) {
     let t_2 = test_json_without_eq(&t, "");
     assert_eq!(t, t_2);
-    return;
 }
 
 fn test_json_with_deps<
@@ -36,7 +100,6 @@ fn test_json_with_deps<
 ) {
     let t_2 = test_json_without_eq(&t, deps);
     assert_eq!(t, t_2);
-    return;
 }
 
 fn test_json_without_eq<
@@ -108,7 +171,46 @@ s
     let json = run_repl(&input);
     let unescaped = unescape::unescape(&json).unwrap();
     println!("{}", unescaped);
-    return serde_json::from_str(&unescaped).unwrap();
+    serde_json::from_str(&unescaped).unwrap()
+}
+
+fn test_decode_error<T: Elm + ElmDecode>(json: &str, deps: &str) -> String {
+    let decoder_type = T::decoder_type();
+    let elm_type = T::elm_definition().unwrap_or_default();
+    let decoder = T::decoder_definition().unwrap_or_default();
+
+    let input = format!(
+        r#"
+import Json.Decode
+import Json.Encode
+import Dict exposing (Dict)
+
+
+{deps}
+
+{elm_type}
+
+{decoder}
+
+decoded = Json.Decode.decodeString {decoder_type} "{json}"
+
+s = case decoded of
+    Ok _ ->
+        "OK"
+    Err err ->
+        Json.Decode.errorToString err
+
+"START"
+s
+"END"
+
+:exit
+"#,
+    );
+
+    let output = run_repl(&input);
+    assert_ne!(output, "OK");
+    output
 }
 
 fn test_query<
@@ -117,6 +219,17 @@ fn test_query<
 >(
     val: T,
     expected: &str,
+) {
+    test_query_with_deps::<T, U>(val, expected, "");
+}
+
+fn test_query_with_deps<
+    T: Elm + ElmEncode + ElmDecode + ElmQuery + Serialize,
+    U: Elm + ElmEncode + ElmDecode + ElmQueryField + Serialize,
+>(
+    val: T,
+    expected: &str,
+    deps: &str,
 ) {
     let json = serde_json::to_string(&val).unwrap().replace("\"", "\\\"");
 
@@ -135,6 +248,8 @@ fn test_query<
 import Json.Decode
 import Url.Builder
 
+{deps}
+
 {u_elm_type}
 
 {elm_type}
@@ -167,6 +282,267 @@ s
     assert_eq!(output, expected);
 }
 
+// like test_query, but the value is given as an Elm expression for types that can't go through the JSON decoders,
+// such as structs with #[serde(flatten)] fields
+fn test_query_with_elm_value<T: Elm + ElmQuery>(value: &str, deps: &str, expected: &str) {
+    let elm_type = T::elm_definition().unwrap();
+    let query = T::elm_query();
+    let query_function = T::query_encoder_type();
+
+    let input = format!(
+        r#"
+import Url.Builder
+
+{deps}
+
+{elm_type}
+
+{query}
+
+"START"
+Url.Builder.toQuery ({query_function} ({value}))
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    assert_eq!(output, expected);
+}
+
+// evaluates an Elm expression that uses the form functions of T
+fn test_form<T: Elm + ElmForm>(expression: &str, expected: &str) {
+    let elm_type = T::elm_definition().unwrap();
+    let form = T::elm_form();
+
+    let input = format!(
+        r#"
+import File
+import Http
+import Url.Builder
+
+{elm_type}
+
+{form}
+
+"START"
+{expression}
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    assert_eq!(output, expected);
+}
+
+// Http.Body can't be inspected, so the urlencoded body is turned into the string it would send
+fn test_url_encoded_form<T: Elm + ElmForm>(value: &str, expected: &str) {
+    let elm_type = T::elm_definition().unwrap();
+    let form = T::elm_form().replace(
+        "Http.stringBody \"application/x-www-form-urlencoded\"",
+        "identity",
+    );
+    let url_encoded_body = T::url_encoded_body_type();
+
+    let input = format!(
+        r#"
+import File
+import Http
+import Url.Builder
+
+{elm_type}
+
+{form}
+
+"START"
+{url_encoded_body} ({value})
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    assert_eq!(output, expected);
+}
+
+fn test_path<T: ElmPath>(value: &str, deps: &str, expected: &str) {
+    let path = T::elm_path();
+    let path_function = T::path_builder_type();
+
+    let input = format!(
+        r#"
+import Url
+import Url.Builder
+
+{deps}
+
+{path}
+
+"START"
+{path_function} ({value})
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    assert_eq!(output, expected);
+}
+
+// parses the path with the route parser of T and turns the route back into a path
+fn test_route<T: Elm + ElmRoute>(path: &str, deps: &str, expected: &str) {
+    let elm_type = T::elm_definition().unwrap();
+    let route = T::elm_route();
+    let parser = T::route_parser_type();
+    let to_string = T::route_to_string_type();
+
+    let input = format!(
+        r#"
+import Url
+import Url.Builder
+import Url.Parser exposing ((</>))
+
+{deps}
+
+{elm_type}
+
+{route}
+
+"START"
+Url.fromString ("http://localhost" ++ "{path}") |> Maybe.andThen (Url.Parser.parse {parser}) |> Maybe.map {to_string} |> Maybe.withDefault "Nothing"
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    assert_eq!(output, expected);
+}
+
+// builds a path with the path builder of P and parses it with the route parser of R
+fn test_path_route<P: ElmPath, R: Elm + ElmRoute>(value: &str, deps: &str, expected: &str) {
+    let path = P::elm_path();
+    let path_function = P::path_builder_type();
+    let elm_type = R::elm_definition().unwrap();
+    let route = R::elm_route();
+    let parser = R::route_parser_type();
+    let to_string = R::route_to_string_type();
+
+    let input = format!(
+        r#"
+import Url
+import Url.Builder
+import Url.Parser exposing ((</>))
+
+{deps}
+
+{path}
+
+{elm_type}
+
+{route}
+
+"START"
+Url.fromString ("http://localhost" ++ {path_function} ({value})) |> Maybe.andThen (Url.Parser.parse {parser}) |> Maybe.map {to_string} |> Maybe.withDefault "Nothing"
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    assert_eq!(output, expected);
+}
+
+// requests can't be sent in the repl, so this only checks that the endpoint function compiles
+fn test_endpoint(deps: &str, endpoint: &str, name: &str) {
+    let definitions = crate::endpoint_definitions();
+
+    let input = format!(
+        r#"
+import Http
+import Json.Decode
+import Json.Encode
+import Url
+import Url.Builder
+
+{deps}
+
+{definitions}
+
+{endpoint}
+
+"START"
+always "ok" {name}
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    assert_eq!(output, "ok");
+}
+
+// parses the query with the ElmQueryParser of T and re-encodes the result as JSON
+fn test_query_parser<
+    T: Elm + ElmEncode + ElmQueryParser + DeserializeOwned + PartialEq + Debug,
+    U: Elm + ElmEncode + ElmQueryField,
+>(
+    query: &str,
+    expected: Option<T>,
+) {
+    let encoder_type = T::encoder_type();
+    let elm_type = T::elm_definition().unwrap();
+    let encoder = T::encoder_definition().unwrap();
+    let parser = T::elm_query_parser();
+    let parser_function = format!("queryParser{}", T::elm_type());
+
+    let u_elm_type = U::elm_definition().unwrap();
+    let u_encoder = U::encoder_definition().unwrap();
+
+    let input = format!(
+        r#"
+import Dict exposing (Dict)
+import Json.Encode
+import Url
+import Url.Parser
+import Url.Parser.Query
+
+{u_elm_type}
+
+{elm_type}
+
+{u_encoder}
+
+{encoder}
+
+{parser}
+
+parsed = Url.fromString "http://localhost/{query}" |> Maybe.andThen (Url.Parser.parse (Url.Parser.query {parser_function})) |> Maybe.andThen identity
+
+s = case parsed of
+    Just value ->
+        Json.Encode.encode 0 ({encoder_type} value)
+    Nothing ->
+        "Nothing"
+
+"START"
+s
+"END"
+
+:exit
+"#,
+    );
+    let output = run_repl(&input);
+    match expected {
+        Some(expected) => {
+            let unescaped = unescape::unescape(&output).unwrap();
+            assert_eq!(serde_json::from_str::<T>(&unescaped).unwrap(), expected);
+        }
+        None => assert_eq!(output, "Nothing"),
+    }
+}
+
 fn run_repl(input: &str) -> String {
     println!("{}", input);
     let mut cmd = Command::new("elm")
@@ -0,0 +1,70 @@
+use crate::{Elm, ElmPath, ElmQuery};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, ElmPath)]
+#[elm(path = "/api/users/{id}/posts/{post_id}")]
+struct UserPost {
+    id: u32,
+    post_id: u32,
+}
+
+#[test]
+fn path() {
+    assert!(UserPost::elm_path().contains(
+        r#"Url.Builder.absolute [ "api", "users", Url.percentEncode (String.fromInt struct.id), "posts", Url.percentEncode (String.fromInt struct.postId) ] []"#
+    ));
+    super::test_path::<UserPost>("{ id = 1, postId = 2 }", "", "/api/users/1/posts/2");
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, ElmPath)]
+#[serde(rename_all = "camelCase")]
+#[elm(path = "/files/{fileName}/")]
+struct RenamedFile {
+    file_name: String,
+}
+
+#[test]
+fn path_renamed() {
+    super::test_path::<RenamedFile>(r#"{ fileName = "a b/c" }"#, "", "/files/a%20b%2Fc/");
+}
+
+#[derive(Serialize, Elm, ElmQuery)]
+struct Search {
+    q: String,
+}
+
+#[derive(Serialize, ElmPath)]
+#[elm(path = "/files/{name}")]
+struct File {
+    name: String,
+    #[elm(query)]
+    search: Search,
+}
+
+#[test]
+fn path_with_query() {
+    let deps = [Search::elm_definition().unwrap(), Search::elm_query()].join("\n\n");
+    super::test_path::<File>(
+        r#"{ name = "file", search = { q = "x y" } }"#,
+        &deps,
+        "/files/file?q=x%20y",
+    );
+}
+
+#[derive(Serialize, ElmPath)]
+#[elm(path = "/users/{id}")]
+struct OptionalUser {
+    id: Option<u32>,
+}
+
+#[test]
+fn path_optional_parameter() {
+    let err = crate::export!("Bindings", &mut vec![], {
+        paths: [OptionalUser],
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "the path parameter `id` needs to have a single value, but its type is `Maybe (Int)`"
+    );
+}
@@ -0,0 +1,96 @@
+use crate::{Elm, ElmDecode, ElmEncode};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct User;
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Id<T> {
+    raw: u32,
+    marker: PhantomData<T>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+#[elm(phantom)]
+struct PhantomId<T> {
+    raw: u32,
+    marker: PhantomData<T>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+#[serde(transparent)]
+#[elm(phantom)]
+struct TransparentId<T> {
+    raw: u32,
+    marker: PhantomData<T>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+#[elm(phantom)]
+enum PhantomEnum<T> {
+    Unit,
+    Struct { a: u32, marker: PhantomData<T> },
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+#[serde(tag = "t")]
+enum PhantomInternal<T> {
+    Struct { a: u32, marker: PhantomData<T> },
+}
+
+#[test]
+fn phantom_field() {
+    super::test_json_with_deps(
+        Id::<User> {
+            raw: 0,
+            marker: PhantomData,
+        },
+        &User::elm_definition().unwrap(),
+    );
+}
+
+#[test]
+fn phantom_type_variable() {
+    assert_eq!(PhantomId::<User>::elm_type(), "PhantomId (User)");
+    super::test_json_with_deps(
+        PhantomId::<User> {
+            raw: 0,
+            marker: PhantomData,
+        },
+        &User::elm_definition().unwrap(),
+    );
+}
+
+#[test]
+fn phantom_transparent() {
+    assert_eq!(
+        TransparentId::<User>::elm_definition().unwrap(),
+        "type TransparentId t\n    = TransparentId (Int)\n"
+    );
+    super::test_json_with_deps(
+        TransparentId::<User> {
+            raw: 0,
+            marker: PhantomData,
+        },
+        &User::elm_definition().unwrap(),
+    );
+}
+
+#[test]
+fn phantom_enum() {
+    super::test_json_with_deps(
+        PhantomEnum::<User>::Struct {
+            a: 0,
+            marker: PhantomData,
+        },
+        &User::elm_definition().unwrap(),
+    );
+    super::test_json_with_deps(
+        PhantomInternal::<User>::Struct {
+            a: 0,
+            marker: PhantomData,
+        },
+        &User::elm_definition().unwrap(),
+    );
+}
@@ -0,0 +1,86 @@
+use crate::{Elm, ElmDecode, ElmEncode};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+use time::{macros::datetime, OffsetDateTime};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Posix {
+    system_time: SystemTime,
+    date_time: DateTime<Utc>,
+    #[serde(with = "time::serde::rfc3339")]
+    rfc3339: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    rfc3339_option: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::timestamp")]
+    timestamp: OffsetDateTime,
+    #[serde(with = "time::serde::timestamp::microseconds")]
+    timestamp_microseconds: OffsetDateTime,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    ts_milliseconds: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    ts_seconds_option: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Timestamped {
+    date_time: DateTime<Utc>,
+}
+
+fn posix_time() -> crate::Settings {
+    crate::Settings {
+        posix_time: true,
+        ..Default::default()
+    }
+}
+
+fn deps() -> String {
+    format!("import Time\n\n{}", crate::posix::definitions().unwrap())
+}
+
+#[test]
+fn posix() {
+    posix_time().scope(|| {
+        assert_eq!(
+            Posix::elm_definition()
+                .unwrap()
+                .matches("Time.Posix")
+                .count(),
+            8
+        );
+        super::test_json_with_deps(
+            Posix {
+                system_time: SystemTime::UNIX_EPOCH + Duration::from_millis(1_659_616_496_789),
+                date_time: Utc.timestamp_millis_opt(1_659_616_496_789).unwrap(),
+                rfc3339: datetime!(2022-08-04 12:34:56.789 +03:00),
+                rfc3339_option: Some(datetime!(1969-07-20 20:17:40 UTC)),
+                timestamp: datetime!(2022-08-04 12:34:56 UTC),
+                timestamp_microseconds: datetime!(2022-08-04 12:34:56.789 UTC),
+                ts_milliseconds: Utc.timestamp_millis_opt(-1_234_567).unwrap(),
+                ts_seconds_option: None,
+            },
+            &deps(),
+        );
+    });
+}
+
+#[test]
+fn posix_offsets() {
+    posix_time().scope(|| {
+        // the offset and the digits beyond milliseconds are dropped when decoding
+        let json = "{\\\"date_time\\\":\\\"2022-08-04T12:34:56.789999+03:00\\\"}";
+        let decoded: Timestamped = super::test_with_json(json, &deps());
+        assert_eq!(
+            decoded.date_time,
+            Utc.timestamp_millis_opt(1_659_605_696_789).unwrap()
+        );
+    });
+}
+
+#[test]
+fn posix_invalid() {
+    posix_time().scope(|| {
+        let err = super::test_decode_error::<DateTime<Utc>>("\\\"2022-08-04\\\"", &deps());
+        assert!(err.contains("Invalid RFC 3339 timestamp"));
+    });
+}
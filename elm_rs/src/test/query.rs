@@ -1,4 +1,4 @@
-use crate::{Elm, ElmDecode, ElmEncode, ElmQuery, ElmQueryField};
+use crate::{Elm, ElmDecode, ElmEncode, ElmQuery, ElmQueryField, ElmQueryParser};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQuery)]
@@ -25,7 +25,9 @@ struct ContainsEnum {
     e: Enum,
 }
 
-#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQueryField)]
+#[derive(
+    Debug, PartialEq, Eq, Hash, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQueryField,
+)]
 enum Enum {
     First,
     Second,
@@ -35,3 +37,526 @@ enum Enum {
 fn query_enum() {
     super::test_query::<_, Enum>(ContainsEnum { e: Enum::First }, "?e=First");
 }
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQuery)]
+struct Addresses {
+    ip: std::net::IpAddr,
+    socket: std::net::SocketAddr,
+    e: Enum,
+}
+
+#[test]
+fn query_addresses() {
+    super::test_query::<_, Enum>(
+        Addresses {
+            ip: std::net::Ipv4Addr::LOCALHOST.into(),
+            socket: ([127, 0, 0, 1], 8080).into(),
+            e: Enum::First,
+        },
+        "?ip=127.0.0.1&socket=127.0.0.1%3A8080&e=First",
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQuery)]
+struct Chars {
+    c: char,
+    wrapping: std::num::Wrapping<u8>,
+    e: Enum,
+}
+
+#[test]
+fn query_char() {
+    super::test_query::<_, Enum>(
+        Chars {
+            c: 'c',
+            wrapping: std::num::Wrapping(1),
+            e: Enum::First,
+        },
+        "?c=c&wrapping=1&e=First",
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQuery)]
+struct Optional {
+    page: u32,
+    name: Option<String>,
+    e: Option<Enum>,
+    limit: Option<u32>,
+}
+
+#[test]
+fn query_optional() {
+    super::test_query::<_, Enum>(
+        Optional {
+            page: 1,
+            name: Some("name".to_string()),
+            e: None,
+            limit: Some(10),
+        },
+        "?page=1&name=name&limit=10",
+    );
+    super::test_query::<_, Enum>(
+        Optional {
+            page: 1,
+            name: None,
+            e: Some(Enum::Second),
+            limit: None,
+        },
+        "?page=1&e=Second",
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQuery)]
+struct Lists {
+    page: u32,
+    tags: Vec<String>,
+    #[elm(query_style = "comma")]
+    ids: std::collections::BTreeSet<u32>,
+    #[elm(query_style = "comma")]
+    empty: Vec<u32>,
+    e: std::collections::HashSet<Enum>,
+    name: Option<String>,
+}
+
+#[test]
+fn query_lists() {
+    super::test_query::<_, Enum>(
+        Lists {
+            page: 1,
+            tags: vec!["a".to_string(), "b c".to_string()],
+            ids: [3, 1, 2].into_iter().collect(),
+            empty: vec![],
+            e: [Enum::First].into_iter().collect(),
+            name: None,
+        },
+        "?page=1&tags=a&tags=b%20c&ids=1%2C2%2C3&e=First",
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQueryField)]
+struct Page(u32);
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQueryField)]
+#[serde(transparent)]
+struct Name {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQuery)]
+struct Scalars {
+    flag: bool,
+    ratio: f64,
+    small: f32,
+    page: Page,
+    name: Name,
+    pages: Vec<Page>,
+    e: Enum,
+}
+
+#[test]
+fn query_scalars_and_newtypes() {
+    let deps = format!(
+        "{}\n\n{}\n\n{}\n\n{}",
+        Page::elm_definition().unwrap(),
+        Page::decoder_definition().unwrap(),
+        Name::elm_definition().unwrap(),
+        Name::decoder_definition().unwrap(),
+    );
+    super::test_query_with_deps::<_, Enum>(
+        Scalars {
+            flag: true,
+            ratio: 0.5,
+            small: 1.5,
+            page: Page(2),
+            name: Name {
+                name: "name".to_string(),
+            },
+            pages: vec![Page(1), Page(3)],
+            e: Enum::First,
+        },
+        "?flag=true&ratio=0.5&small=1.5&page=2&name=name&pages=1&pages=3&e=First",
+        &deps,
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQueryField)]
+struct WrappedEnum(Enum);
+
+#[test]
+fn query_newtype_encoder_definition() {
+    // the newtype's encoder uses the inner type's encoder, so its definition is needed as well
+    assert!(WrappedEnum::query_field_encoder().contains("queryFieldEncoderEnum"));
+    assert_eq!(
+        WrappedEnum::query_field_encoder_definition(),
+        Enum::query_field_encoder_definition()
+    );
+    assert!(WrappedEnum::query_field_encoder_definition().is_some());
+}
+
+#[derive(
+    Debug, PartialEq, Deserialize, Serialize, Elm, ElmDecode, ElmEncode, ElmQuery, ElmQueryParser,
+)]
+#[serde(rename_all = "camelCase")]
+struct Parsed {
+    page_number: u32,
+    #[serde(rename = "q")]
+    search: Option<String>,
+    #[serde(default)]
+    flag: bool,
+    #[serde(default)]
+    ratio: f64,
+    tags: Vec<String>,
+    #[elm(query_style = "comma")]
+    ids: Vec<u32>,
+    e: Option<Enum>,
+}
+
+#[test]
+fn query_parser() {
+    super::test_query_parser::<_, Enum>(
+        "?pageNumber=2&q=a%20b&flag=true&ratio=0.5&tags=a&tags=b&ids=1%2C2&e=Second",
+        Some(Parsed {
+            page_number: 2,
+            search: Some("a b".to_string()),
+            flag: true,
+            ratio: 0.5,
+            tags: vec!["a".to_string(), "b".to_string()],
+            ids: vec![1, 2],
+            e: Some(Enum::Second),
+        }),
+    );
+}
+
+#[test]
+fn query_parser_missing_fields() {
+    super::test_query_parser::<_, Enum>(
+        "?pageNumber=1",
+        Some(Parsed {
+            page_number: 1,
+            search: None,
+            flag: false,
+            ratio: 0.0,
+            tags: vec![],
+            ids: vec![],
+            e: None,
+        }),
+    );
+    super::test_query_parser::<Parsed, Enum>("?q=a", None);
+    super::test_query_parser::<Parsed, Enum>("?pageNumber=1&e=Third", None);
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmQueryParser)]
+#[serde(default)]
+struct Defaults {
+    page: u32,
+    flag: bool,
+    tags: Vec<String>,
+    e: Option<Enum>,
+}
+
+#[test]
+fn query_parser_container_default() {
+    let expected = Defaults {
+        flag: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_qs::from_str::<Defaults>("flag=true").unwrap(),
+        expected
+    );
+    super::test_query_parser::<_, Enum>("?flag=true", Some(expected));
+}
+
+#[derive(Serialize, Elm, ElmQuery, ElmQueryParser)]
+struct MissingDefault {
+    #[serde(default)]
+    e: Enum,
+}
+
+#[test]
+fn query_parser_missing_default() {
+    crate::export!("Bindings", &mut vec![], {
+        queries: [MissingDefault],
+    })
+    .unwrap();
+    let err = crate::export!("Bindings", &mut vec![], {
+        query_parsers: [MissingDefault],
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`e` has `#[serde(default)]`, but `elm_rs::test::query::Enum` has no default value in Elm"
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmQuery, ElmQueryParser)]
+struct Sorting {
+    sort: String,
+    order: Enum,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmQuery)]
+struct Filter {
+    name: String,
+    #[serde(flatten)]
+    sorting: Sorting,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmQuery)]
+struct Search {
+    q: String,
+    #[serde(flatten)]
+    sorting: Sorting,
+    #[elm(query_style = "brackets")]
+    filter: Filter,
+}
+
+#[test]
+fn query_nested() {
+    let expected = "?q=x&sort=name&order=First&filter%5Bname%5D=y&filter%5Bsort%5D=date&filter%5Border%5D=Second";
+    // Url.Builder percent-encodes the brackets, which serde_qs only accepts in non-strict mode
+    let search: Search = serde_qs::Config::new(5, false)
+        .deserialize_str(&expected[1..])
+        .unwrap();
+    assert_eq!(
+        search,
+        Search {
+            q: "x".to_string(),
+            sorting: Sorting {
+                sort: "name".to_string(),
+                order: Enum::First,
+            },
+            filter: Filter {
+                name: "y".to_string(),
+                sorting: Sorting {
+                    sort: "date".to_string(),
+                    order: Enum::Second,
+                },
+            },
+        }
+    );
+
+    let deps = [
+        Enum::elm_definition().unwrap(),
+        Enum::query_field_encoder_definition().unwrap(),
+        Sorting::elm_definition().unwrap(),
+        Sorting::elm_query(),
+        Filter::elm_definition().unwrap(),
+    ]
+    .join("\n\n");
+    super::test_query_with_elm_value::<Search>(
+        r#"{ q = "x", sorting = { sort = "name", order = First }, filter = { name = "y", sorting = { sort = "date", order = Second } } }"#,
+        &deps,
+        expected,
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmQuery)]
+struct Saved {
+    id: u32,
+    #[elm(query_style = "brackets")]
+    search: Search,
+}
+
+#[test]
+fn query_nested_twice() {
+    let saved = Saved {
+        id: 1,
+        search: Search {
+            q: "x".to_string(),
+            sorting: Sorting {
+                sort: "name".to_string(),
+                order: Enum::First,
+            },
+            filter: Filter {
+                name: "y".to_string(),
+                sorting: Sorting {
+                    sort: "date".to_string(),
+                    order: Enum::Second,
+                },
+            },
+        },
+    };
+    // Url.Builder percent-encodes the brackets that serde_qs leaves as they are
+    let expected = format!(
+        "?{}",
+        serde_qs::to_string(&saved)
+            .unwrap()
+            .replace('[', "%5B")
+            .replace(']', "%5D")
+    );
+    let saved_from_query: Saved = serde_qs::Config::new(5, false)
+        .deserialize_str(&expected[1..])
+        .unwrap();
+    assert_eq!(saved_from_query, saved);
+    assert!(Saved::elm_query().contains(r#"Url.Builder.string "search[filter][name]""#));
+
+    let deps = [
+        Enum::elm_definition().unwrap(),
+        Enum::query_field_encoder_definition().unwrap(),
+        Sorting::elm_definition().unwrap(),
+        Sorting::elm_query(),
+        Filter::elm_definition().unwrap(),
+        Search::elm_definition().unwrap(),
+    ]
+    .join("\n\n");
+    super::test_query_with_elm_value::<Saved>(
+        r#"{ id = 1, search = { q = "x", sorting = { sort = "name", order = First }, filter = { name = "y", sorting = { sort = "date", order = Second } } } }"#,
+        &deps,
+        &expected,
+    );
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmQuery, ElmQueryParser)]
+struct Sorted {
+    q: String,
+    #[serde(flatten)]
+    sorting: Sorting,
+}
+
+#[test]
+fn query_parser_flattened() {
+    let parser = Sorted::elm_query_parser();
+    assert!(parser.contains("|> Url.Parser.Query.map2 (Maybe.map2 (|>)) (queryParserSorting)"));
+}
+
+#[derive(Debug, PartialEq, Serialize, Elm, ElmQuery)]
+#[serde(untagged, rename_all_fields = "camelCase")]
+enum SearchQuery {
+    ByName {
+        full_name: String,
+        exact_match: bool,
+    },
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    ById {
+        user_id: u32,
+        #[serde(rename = "v")]
+        version: Option<u32>,
+    },
+}
+
+#[test]
+fn query_untagged_enum() {
+    let by_name = SearchQuery::ByName {
+        full_name: "x".to_string(),
+        exact_match: true,
+    };
+    assert_eq!(
+        serde_qs::to_string(&by_name).unwrap(),
+        "fullName=x&exactMatch=true"
+    );
+    super::test_query_with_elm_value::<SearchQuery>(
+        r#"ByName { fullName = "x", exactMatch = True }"#,
+        "",
+        "?fullName=x&exactMatch=true",
+    );
+
+    let by_id = SearchQuery::ById {
+        user_id: 1,
+        version: Some(2),
+    };
+    assert_eq!(serde_qs::to_string(&by_id).unwrap(), "USER_ID=1&v=2");
+    super::test_query_with_elm_value::<SearchQuery>(
+        "ById { userId = 1, version = Just 2 }",
+        "",
+        "?USER_ID=1&v=2",
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Elm, ElmQuery)]
+#[serde(tag = "by", rename_all = "lowercase")]
+enum Lookup {
+    Name {
+        name: String,
+    },
+    #[serde(rename = "identifier")]
+    Id {
+        id: u32,
+    },
+    All,
+}
+
+#[derive(Debug, PartialEq, Serialize, Elm, ElmQuery)]
+struct LookupPage {
+    #[serde(flatten)]
+    lookup: Lookup,
+    page: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Elm, ElmQuery)]
+struct NestedLookup {
+    #[elm(query_style = "brackets")]
+    lookup: Lookup,
+}
+
+#[test]
+fn query_internally_tagged_enum() {
+    let err = crate::export!("Bindings", &mut vec![], {
+        queries: [Lookup, NestedLookup],
+    })
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("can't be nested with the brackets query style"));
+
+    let page = LookupPage {
+        lookup: Lookup::Id { id: 1 },
+        page: 2,
+    };
+    assert_eq!(
+        serde_qs::to_string(&page).unwrap(),
+        "by=identifier&id=1&page=2"
+    );
+    assert_eq!(
+        serde_qs::to_string(&Lookup::Name {
+            name: "x".to_string()
+        })
+        .unwrap(),
+        "by=name&name=x"
+    );
+    assert_eq!(serde_qs::to_string(&Lookup::All).unwrap(), "by=all");
+    let deps = [Lookup::elm_definition().unwrap(), Lookup::elm_query()].join("\n\n");
+    super::test_query_with_elm_value::<LookupPage>(
+        "{ lookup = Id { id = 1 }, page = 2 }",
+        &deps,
+        "?by=identifier&id=1&page=2",
+    );
+    super::test_query_with_elm_value::<Lookup>("All", "", "?by=all");
+}
+
+#[derive(Serialize, Elm, ElmQuery, ElmQueryParser)]
+struct NestedOptional {
+    value: Option<Option<u8>>,
+}
+
+#[test]
+fn query_nested_optional() {
+    let err = crate::export!("Bindings", &mut vec![], {
+        queries: [NestedOptional],
+        query_parsers: [NestedOptional],
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "nested options such as `Option<core::option::Option<u8>>` can't be used in queries"
+    );
+}
+
+#[derive(Serialize, Elm, ElmQuery, ElmQueryParser)]
+struct CommaSeparatedScalar {
+    #[elm(query_style = "comma")]
+    tags: String,
+}
+
+#[test]
+fn query_style_without_list() {
+    let err = crate::export!("Bindings", &mut vec![], {
+        queries: [CommaSeparatedScalar],
+        query_parsers: [CommaSeparatedScalar],
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "the comma query style of `tags` can only be used with lists"
+    );
+}
@@ -0,0 +1,72 @@
+use crate::{Elm, ElmPath, ElmQueryField, ElmRoute};
+
+#[allow(dead_code)]
+#[derive(Elm, ElmRoute)]
+enum Route {
+    #[elm(path = "/")]
+    Home,
+    #[elm(path = "/users/:id")]
+    User { id: u32 },
+    #[elm(path = "/users/{id}/posts/{tab}")]
+    Post { id: u32, tab: Tab },
+    #[elm(path = "/files/:name/")]
+    File { name: String },
+}
+
+#[allow(dead_code)]
+#[derive(Elm, ElmQueryField)]
+enum Tab {
+    Comments,
+    Likes,
+}
+
+#[test]
+fn route() {
+    assert!(Route::elm_route().contains(
+        r#"Url.Parser.map (\elmRsId -> User { id = elmRsId }) (Url.Parser.s "users" </> Url.Parser.custom "ID" (Url.percentDecode >> Maybe.andThen String.toInt))"#
+    ));
+    let deps = [
+        Tab::elm_definition().unwrap(),
+        Tab::query_field_encoder_definition().unwrap(),
+    ]
+    .join("\n\n");
+    for path in ["/", "/users/1", "/users/1/posts/Likes", "/files/a%20b/"] {
+        super::test_route::<Route>(path, &deps, path);
+    }
+    super::test_route::<Route>("/users/x", &deps, "Nothing");
+}
+
+#[allow(dead_code)]
+#[derive(Elm, ElmPath)]
+#[elm(path = "/files/:name/")]
+struct FilePath {
+    name: String,
+}
+
+#[test]
+fn path_to_route() {
+    assert!(Route::elm_route().contains(
+        r#"File struct ->
+            Url.Builder.absolute [ "files", Url.percentEncode (identity struct.name), "" ] []"#
+    ));
+    super::test_path_route::<FilePath, Route>(r#"{ name = "a b" }"#, "", "/files/a%20b/");
+}
+
+#[allow(dead_code)]
+#[derive(Elm, ElmRoute)]
+enum ListRoute {
+    #[elm(path = "/tags/:tags")]
+    Tags { tags: Vec<String> },
+}
+
+#[test]
+fn route_list_parameter() {
+    let err = crate::export!("Bindings", &mut vec![], {
+        routes: [ListRoute],
+    })
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "the path parameter `tags` needs to have a single value, but its type is `List (String)`"
+    );
+}
@@ -0,0 +1,96 @@
+use crate::{Elm, ElmDecode, ElmEncode, LargeIntegers, Settings};
+use serde::{Deserialize, Serialize};
+use time::{macros::datetime, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Times {
+    date: Date,
+    duration: Duration,
+    offset_date_time: OffsetDateTime,
+    primitive_date_time: PrimitiveDateTime,
+    time: Time,
+    #[serde(with = "time::serde::rfc3339")]
+    rfc3339: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    rfc3339_option: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::timestamp")]
+    timestamp: OffsetDateTime,
+    #[serde(with = "time::serde::timestamp::milliseconds")]
+    timestamp_milliseconds: OffsetDateTime,
+}
+
+#[derive(Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Timestamps {
+    #[serde(with = "time::serde::timestamp::nanoseconds")]
+    timestamp_nanoseconds: OffsetDateTime,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Created(#[serde(with = "time::serde::rfc3339")] OffsetDateTime);
+
+#[test]
+fn times() {
+    let offset_date_time = datetime!(2022-08-04 12:34:56.789 +03:00);
+    let primitive_date_time = datetime!(2022-08-04 12:34:56.789);
+    let deps = [
+        Date::elm_definition(),
+        Date::encoder_definition(),
+        Date::decoder_definition(),
+        Duration::elm_definition(),
+        Duration::encoder_definition(),
+        Duration::decoder_definition(),
+        OffsetDateTime::elm_definition(),
+        OffsetDateTime::encoder_definition(),
+        OffsetDateTime::decoder_definition(),
+        PrimitiveDateTime::elm_definition(),
+        PrimitiveDateTime::encoder_definition(),
+        PrimitiveDateTime::decoder_definition(),
+        Time::elm_definition(),
+        Time::encoder_definition(),
+        Time::decoder_definition(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("\n\n");
+    super::test_json_with_deps(
+        Times {
+            date: primitive_date_time.date(),
+            duration: Duration::new(-12, -345),
+            offset_date_time,
+            primitive_date_time,
+            time: primitive_date_time.time(),
+            rfc3339: offset_date_time,
+            rfc3339_option: Some(offset_date_time),
+            timestamp: datetime!(2022-08-04 12:34:56 UTC),
+            timestamp_milliseconds: datetime!(2022-08-04 12:34:56.789 UTC),
+        },
+        &deps,
+    );
+}
+
+#[test]
+fn timestamps_i128() {
+    let settings = Settings {
+        large_integers: LargeIntegers::Deny,
+        ..Default::default()
+    };
+    let err = crate::export!("Bindings", &mut vec![], {
+        settings: settings,
+        encoders: [Timestamps],
+        decoders: [Timestamps],
+    })
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("`i128` can't be represented exactly in Elm"));
+}
+
+#[test]
+fn newtype_with() {
+    // the newtype uses the representation of the serde module instead of the tuple of OffsetDateTime
+    assert!(Created::elm_definition()
+        .unwrap()
+        .contains("= Created (String)"));
+    super::test_json(Created(datetime!(2022-08-04 12:34:56.789 +03:00)));
+}
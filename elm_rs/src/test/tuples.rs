@@ -0,0 +1,61 @@
+use crate::{Elm, ElmDecode, ElmEncode};
+use serde::{Deserialize, Serialize};
+
+type Four = (u8, u32, String, bool);
+type Twelve = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, String);
+type Nested = (u8, u8, u8, (u8, u8, u8, u8));
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Tuples {
+    four: Four,
+    twelve: Twelve,
+    nested: Vec<Nested>,
+}
+
+#[test]
+fn tuples() {
+    assert_eq!(Four::elm_type(), "Tuple4 (Int) (Int) (String) (Bool)");
+    super::test_json_with_deps(
+        Tuples {
+            four: (1, 2, "3".to_string(), true),
+            twelve: (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, "12".to_string()),
+            nested: vec![(1, 2, 3, (4, 5, 6, 7))],
+        },
+        &format!(
+            "\
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+",
+            Four::elm_definition().unwrap(),
+            Four::encoder_definition().unwrap(),
+            Four::decoder_definition().unwrap(),
+            Twelve::elm_definition().unwrap(),
+            Twelve::encoder_definition().unwrap(),
+            Twelve::decoder_definition().unwrap(),
+        ),
+    );
+}
+
+#[test]
+fn tuples_exported_once() {
+    let mut target = vec![];
+    crate::export!("Bindings", &mut target, {
+        encoders: [Four, (u8, u8, u8, u8), Nested],
+        decoders: [Four, (u8, u8, u8, u8), Nested],
+    })
+    .unwrap();
+    let output = String::from_utf8(target).unwrap();
+    assert_eq!(output.matches("type alias Tuple4 a b c d =").count(), 1);
+    assert_eq!(output.matches("\ntuple4Encoder :").count(), 1);
+    assert_eq!(output.matches("\ntuple4Decoder :").count(), 1);
+}
@@ -3,9 +3,12 @@ use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
     cell::{Cell, RefCell},
+    cmp::Reverse,
     collections::*,
     hash::Hash,
+    net::*,
     num::*,
+    ops::{Bound, Range, RangeInclusive},
     path::PathBuf,
     rc::Rc,
     sync::{atomic::*, Arc, Mutex, RwLock},
@@ -33,12 +36,18 @@ struct Types<T: Copy + Hash + Eq> {
     ausize: AtomicUsize,
     btreemap: BTreeMap<String, T>,
     btreeset: BTreeSet<u8>,
+    binaryheap: BinaryHeap<u8>,
+    bound_included: Bound<T>,
+    bound_unbounded: Bound<T>,
     b: Box<T>,
     cell: Cell<T>,
     cow: Cow<'static, u8>,
     duration: Duration,
     hashmap: HashMap<String, T>,
     hashset: HashSet<T>,
+    ipaddr: IpAddr,
+    ipv4addr: Ipv4Addr,
+    ipv6addr: Ipv6Addr,
     linkedlist: LinkedList<T>,
     mutex: Mutex<T>,
     nu8: NonZeroU8,
@@ -54,15 +63,24 @@ struct Types<T: Copy + Hash + Eq> {
     option_some: Option<T>,
     option_none: Option<T>,
     pathbuf: PathBuf,
+    range: Range<T>,
+    range_inclusive: RangeInclusive<T>,
     rc: Rc<T>,
+    reverse: Reverse<T>,
     refcell: RefCell<T>,
     result: Result<T, T>,
     rwlock: RwLock<T>,
+    socketaddr: SocketAddr,
+    socketaddrv4: SocketAddrV4,
+    socketaddrv6: SocketAddrV6,
     string: String,
     systemtime: SystemTime,
     vec: Vec<T>,
+    vecdeque: VecDeque<T>,
+    wrapping: Wrapping<T>,
     array: [T; 2],
     bool: bool,
+    char: char,
     f32: f32,
     f64: f64,
     u8: u8,
@@ -100,12 +118,18 @@ fn types() {
             ausize: AtomicUsize::default(),
             btreemap: BTreeMap::default(),
             btreeset: BTreeSet::default(),
+            binaryheap: BinaryHeap::from([0]),
+            bound_included: Bound::Included(0),
+            bound_unbounded: Bound::Unbounded,
             b: Box::new(0),
             cell: Cell::new(0),
             cow: Cow::Owned(0),
             duration: Duration::from_secs(0),
             hashmap: HashMap::default(),
             hashset: HashSet::default(),
+            ipaddr: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            ipv4addr: Ipv4Addr::LOCALHOST,
+            ipv6addr: Ipv6Addr::LOCALHOST,
             linkedlist: LinkedList::default(),
             mutex: Mutex::new(0),
             nu8: NonZeroU8::new(1).unwrap(),
@@ -121,15 +145,24 @@ fn types() {
             option_some: Some(0),
             option_none: None,
             pathbuf: PathBuf::default(),
+            range: 0..1,
+            range_inclusive: 0..=1,
             rc: Rc::new(0),
+            reverse: Reverse(0),
             refcell: RefCell::new(0),
             result: Err(0),
             rwlock: RwLock::new(0),
+            socketaddr: SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 8080),
+            socketaddrv4: SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080),
+            socketaddrv6: SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0, 0),
             string: "0".to_string(),
             systemtime: SystemTime::UNIX_EPOCH,
             vec: vec![0, 0],
+            vecdeque: VecDeque::from([0]),
+            wrapping: Wrapping(0),
             array: [0, 0],
             bool: false,
+            char: 'ö',
             f32: 0.0,
             f64: 0.0,
             u8: 0,
@@ -161,6 +194,24 @@ fn types() {
 
 {}
 
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
+{}
+
 ",
             std::time::Duration::elm_definition().unwrap(),
             std::time::Duration::encoder_definition().unwrap(),
@@ -170,6 +221,20 @@ fn types() {
             std::time::SystemTime::decoder_definition().unwrap(),
             Result::<(), ()>::encoder_definition().unwrap(),
             Result::<(), ()>::decoder_definition().unwrap(),
+            Range::<()>::elm_definition().unwrap(),
+            Range::<()>::encoder_definition().unwrap(),
+            Range::<()>::decoder_definition().unwrap(),
+            RangeInclusive::<()>::elm_definition().unwrap(),
+            RangeInclusive::<()>::encoder_definition().unwrap(),
+            RangeInclusive::<()>::decoder_definition().unwrap(),
+            Bound::<()>::elm_definition().unwrap(),
+            Bound::<()>::encoder_definition().unwrap(),
+            Bound::<()>::decoder_definition().unwrap(),
         ),
     );
 }
+
+#[test]
+fn char_with_multiple_characters() {
+    super::test_decode_error::<char>("\\\"ab\\\"", "");
+}
@@ -0,0 +1,53 @@
+use crate::{Elm, ElmDecode, ElmEncode, ElmQuery, ElmQueryField};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode, ElmQuery)]
+struct Web {
+    url: url::Url,
+    version: semver::Version,
+    version_req: semver::VersionReq,
+    #[serde(with = "super::display_from_str")]
+    mime: mime::Mime,
+    #[serde(with = "http_serde::method")]
+    method: http::Method,
+    #[serde(with = "http_serde::uri")]
+    uri: http::Uri,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode)]
+struct Status {
+    #[serde(with = "http_serde::status_code")]
+    status: http::StatusCode,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Elm, ElmEncode, ElmDecode, ElmQueryField)]
+enum Unused {
+    Unused,
+}
+
+fn web() -> Web {
+    Web {
+        url: "https://example.com/path?query=1".parse().unwrap(),
+        version: "1.2.3-beta.1".parse().unwrap(),
+        version_req: ">=1.2, <2".parse().unwrap(),
+        mime: mime::APPLICATION_JSON,
+        method: http::Method::PATCH,
+        uri: "/path?query=1".parse().unwrap(),
+    }
+}
+
+#[test]
+fn web_types() {
+    super::test_json(web());
+    super::test_json(Status {
+        status: http::StatusCode::IM_A_TEAPOT,
+    });
+}
+
+#[test]
+fn web_types_query() {
+    super::test_query::<_, Unused>(
+        web(),
+        "?url=https%3A%2F%2Fexample.com%2Fpath%3Fquery%3D1&version=1.2.3-beta.1&version_req=%3E%3D1.2%2C%20%3C2&mime=application%2Fjson&method=PATCH&uri=%2Fpath%3Fquery%3D1",
+    );
+}
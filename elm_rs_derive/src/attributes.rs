@@ -4,6 +4,7 @@ use syn::Attribute;
 
 #[derive(Default)]
 pub struct ContainerAttributes {
+    pub elm: elm::ContainerAttributes,
     #[cfg(feature = "serde")]
     pub serde: serde::ContainerAttributes,
 }
@@ -12,6 +13,9 @@ impl ContainerAttributes {
     pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
         let mut attributes = Self::default();
         for attr in attrs {
+            if attr.path().is_ident("elm") {
+                attributes.elm.parse(attr)?;
+            }
             #[cfg(feature = "serde")]
             if attr.path().is_ident("serde") {
                 attributes.serde.parse(attr)?;
@@ -23,6 +27,7 @@ impl ContainerAttributes {
 
 #[derive(Default)]
 pub struct VariantAttributes {
+    pub elm: elm::VariantAttributes,
     #[cfg(feature = "serde")]
     pub serde: serde::VariantAttributes,
 }
@@ -32,6 +37,9 @@ impl VariantAttributes {
         let mut attributes = Self::default();
 
         for attr in attrs {
+            if attr.path().is_ident("elm") {
+                attributes.elm.parse(attr)?;
+            }
             #[cfg(feature = "serde")]
             if attr.path().is_ident("serde") {
                 attributes.serde.parse(attr)?;
@@ -44,6 +52,7 @@ impl VariantAttributes {
 
 #[derive(Default)]
 pub struct FieldAttributes {
+    pub elm: elm::FieldAttributes,
     #[cfg(feature = "serde")]
     pub serde: serde::FieldAttributes,
 }
@@ -53,6 +62,9 @@ impl FieldAttributes {
         let mut attributes = Self::default();
 
         for attr in attrs {
+            if attr.path().is_ident("elm") {
+                attributes.elm.parse(attr)?;
+            }
             #[cfg(feature = "serde")]
             if attr.path().is_ident("serde") {
                 attributes.serde.parse(attr)?;
@@ -63,6 +75,191 @@ impl FieldAttributes {
     }
 }
 
+pub mod elm {
+    use proc_macro2::Ident;
+    use syn::{Attribute, LitStr, Token};
+
+    #[derive(Default)]
+    pub struct ContainerAttributes {
+        pub phantom: bool,
+        // the path template for ElmPath, e.g. "/users/{id}"
+        pub path: Option<LitStr>,
+    }
+
+    impl ContainerAttributes {
+        pub fn parse(&mut self, attr: &Attribute) -> syn::Result<()> {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("phantom") {
+                    self.phantom = true;
+                    Ok(())
+                } else if meta.path.is_ident("path") {
+                    // path = ".."
+                    meta.input.parse::<Token![=]>()?;
+                    self.path = Some(meta.input.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported elm attribute"))
+                }
+            })
+        }
+    }
+
+    #[derive(Default)]
+    pub struct VariantAttributes {
+        // the path template of the variant for ElmRoute, e.g. "/users/{id}"
+        pub path: Option<LitStr>,
+    }
+
+    impl VariantAttributes {
+        pub fn parse(&mut self, attr: &Attribute) -> syn::Result<()> {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    // path = ".."
+                    meta.input.parse::<Token![=]>()?;
+                    self.path = Some(meta.input.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported elm attribute"))
+                }
+            })
+        }
+    }
+
+    #[derive(Default)]
+    pub struct FieldAttributes {
+        // the name of the marker type in elm_rs::large_integers
+        pub large_integers: Option<Ident>,
+        pub query_style: Option<QueryStyle>,
+        // the field is the query of an ElmPath instead of a path segment
+        pub query: bool,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum QueryStyle {
+        // list-valued query fields repeat the key for each value, the default for lists
+        Repeated,
+        // list-valued query fields are joined with commas
+        Comma,
+        // the fields of nested structs are nested under the key like serde_qs, e.g. `filter[name]=x`
+        Brackets,
+    }
+
+    impl FieldAttributes {
+        pub fn parse(&mut self, attr: &Attribute) -> syn::Result<()> {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("large_integers") {
+                    // large_integers = ".."
+                    meta.input.parse::<Token![=]>()?;
+                    let content = meta.input.parse::<LitStr>()?;
+                    let policy = match content.value().as_str() {
+                        "int" => "Int",
+                        "checked" => "Checked",
+                        "string" => "String",
+                        "deny" => "Deny",
+                        _ => {
+                            return Err(syn::Error::new(
+                                content.span(),
+                                "expected one of \"int\", \"checked\", \"string\" or \"deny\"",
+                            ))
+                        }
+                    };
+                    self.large_integers = Some(Ident::new(policy, content.span()));
+                    Ok(())
+                } else if meta.path.is_ident("query_style") {
+                    // query_style = ".."
+                    meta.input.parse::<Token![=]>()?;
+                    let content = meta.input.parse::<LitStr>()?;
+                    self.query_style = Some(match content.value().as_str() {
+                        "repeated" => QueryStyle::Repeated,
+                        "comma" => QueryStyle::Comma,
+                        "brackets" => QueryStyle::Brackets,
+                        _ => {
+                            return Err(syn::Error::new(
+                                content.span(),
+                                "expected one of \"repeated\", \"comma\" or \"brackets\"",
+                            ))
+                        }
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("query") {
+                    self.query = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported elm attribute"))
+                }
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn parses_container_phantom() {
+            let mut ca = ContainerAttributes::default();
+            ca.parse(&syn::parse_quote!(#[elm(phantom)])).unwrap();
+            assert!(ca.phantom);
+        }
+
+        #[test]
+        fn parses_container_path() {
+            let mut ca = ContainerAttributes::default();
+            ca.parse(&syn::parse_quote!(#[elm(path = "/users/{id}")]))
+                .unwrap();
+            assert_eq!(ca.path.unwrap().value(), "/users/{id}");
+        }
+
+        #[test]
+        fn rejects_unknown_container_attribute() {
+            let mut ca = ContainerAttributes::default();
+            assert!(ca.parse(&syn::parse_quote!(#[elm(unknown)])).is_err());
+        }
+
+        #[test]
+        fn parses_variant_path() {
+            let mut va = VariantAttributes::default();
+            va.parse(&syn::parse_quote!(#[elm(path = "/users/:id")]))
+                .unwrap();
+            assert_eq!(va.path.as_ref().unwrap().value(), "/users/:id");
+            assert!(va.parse(&syn::parse_quote!(#[elm(phantom)])).is_err());
+        }
+
+        #[test]
+        fn parses_field_query_style() {
+            let mut fa = FieldAttributes::default();
+            fa.parse(&syn::parse_quote!(#[elm(query_style = "comma")]))
+                .unwrap();
+            assert_eq!(fa.query_style, Some(QueryStyle::Comma));
+            fa.parse(&syn::parse_quote!(#[elm(query_style = "repeated")]))
+                .unwrap();
+            assert_eq!(fa.query_style, Some(QueryStyle::Repeated));
+            fa.parse(&syn::parse_quote!(#[elm(query_style = "brackets")]))
+                .unwrap();
+            assert_eq!(fa.query_style, Some(QueryStyle::Brackets));
+            assert!(fa
+                .parse(&syn::parse_quote!(#[elm(query_style = "dots")]))
+                .is_err());
+        }
+
+        #[test]
+        fn parses_field_large_integers() {
+            let mut fa = FieldAttributes::default();
+            fa.parse(&syn::parse_quote!(#[elm(large_integers = "string")]))
+                .unwrap();
+            assert_eq!(fa.large_integers.unwrap(), "String");
+        }
+
+        #[test]
+        fn rejects_unknown_large_integers() {
+            let mut fa = FieldAttributes::default();
+            assert!(fa
+                .parse(&syn::parse_quote!(#[elm(large_integers = "float")]))
+                .is_err());
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 pub mod serde {
     use heck::{
@@ -126,6 +323,8 @@ pub mod serde {
         pub rename_all_fields_serialize: Option<RenameAll>,
         pub enum_representation: EnumRepresentation,
         pub transparent: bool,
+        pub deny_unknown_fields: bool,
+        pub default: bool,
     }
 
     impl ContainerAttributes {
@@ -239,6 +438,20 @@ pub mod serde {
                     self.transparent = true;
                 }
 
+                if meta.path.is_ident("deny_unknown_fields") {
+                    self.deny_unknown_fields = true;
+                }
+
+                if meta.path.is_ident("default") {
+                    // default or default = ".."
+                    if meta.input.parse::<Token![=]>().is_ok() {
+                        // the values returned by the function aren't known, so the fields are treated as required
+                        meta.input.parse::<LitStr>()?;
+                    } else {
+                        self.default = true;
+                    }
+                }
+
                 // we don't need to handle all serde attributes
                 Ok(())
             })?;
@@ -352,6 +565,8 @@ pub mod serde {
         pub aliases: Vec<String>,
         pub flatten: bool,
         pub skip: bool,
+        pub with: Option<String>,
+        pub default: bool,
     }
 
     impl FieldAttributes {
@@ -397,6 +612,22 @@ pub mod serde {
                     self.skip = true;
                 }
 
+                if meta.path.is_ident("with") && meta.input.parse::<Token![=]>().is_ok() {
+                    // with = ".."
+                    let content = meta.input.parse::<LitStr>()?;
+                    self.with = Some(content.value());
+                }
+
+                if meta.path.is_ident("default") {
+                    // default or default = ".."
+                    if meta.input.parse::<Token![=]>().is_ok() {
+                        // the value returned by the function isn't known, so the field is treated as required
+                        meta.input.parse::<LitStr>()?;
+                    } else {
+                        self.default = true;
+                    }
+                }
+
                 Ok(())
             })?;
 
@@ -539,6 +770,24 @@ pub mod serde {
             ));
         }
 
+        #[test]
+        fn parses_container_deny_unknown_fields() {
+            let mut ca = ContainerAttributes::default();
+            ca.parse(&syn::parse_quote!(#[serde(deny_unknown_fields)]))
+                .unwrap();
+            assert!(ca.deny_unknown_fields);
+        }
+
+        #[test]
+        fn parses_container_default() {
+            let mut ca = ContainerAttributes::default();
+            ca.parse(&syn::parse_quote!(#[serde(default = "default_query")]))
+                .unwrap();
+            assert!(!ca.default);
+            ca.parse(&syn::parse_quote!(#[serde(default)])).unwrap();
+            assert!(ca.default);
+        }
+
         #[test]
         fn parses_variant_rename() {
             let mut va = VariantAttributes::default();
@@ -666,5 +915,23 @@ pub mod serde {
             fa.parse(&syn::parse_quote!(#[serde(skip)])).unwrap();
             assert!(fa.skip);
         }
+
+        #[test]
+        fn parses_field_with() {
+            let mut fa = FieldAttributes::default();
+            fa.parse(&syn::parse_quote!(#[serde(with = "time::serde::rfc3339")]))
+                .unwrap();
+            assert_eq!(fa.with, Some("time::serde::rfc3339".to_string()));
+        }
+
+        #[test]
+        fn parses_field_default() {
+            let mut fa = FieldAttributes::default();
+            fa.parse(&syn::parse_quote!(#[serde(default = "default_page")]))
+                .unwrap();
+            assert!(!fa.default);
+            fa.parse(&syn::parse_quote!(#[serde(default)])).unwrap();
+            assert!(fa.default);
+        }
     }
 }
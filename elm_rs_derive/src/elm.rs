@@ -1,6 +1,8 @@
 //! Derive macro for Elm.
 
-use super::{EnumVariant, EnumVariantKind, Intermediate, StructField, TypeInfo};
+use super::{
+    phantom_type_variables, EnumVariant, EnumVariantKind, Intermediate, StructField, TypeInfo,
+};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
@@ -24,24 +26,42 @@ fn intermediate_to_token_stream(
         generics_without_bounds,
         type_info,
         container_attributes: _,
+        phantom_params,
     }: Intermediate,
 ) -> TokenStream2 {
+    let type_variables = phantom_type_variables(&phantom_params);
     let type_definition = match type_info {
-        TypeInfo::Unit => unit(&elm_type),
-        TypeInfo::Newtype(ty) => newtype(&elm_type, &ty),
-        TypeInfo::Tuple(tys) => tuple(&elm_type, &tys),
-        TypeInfo::Struct(fields) => struct_type(&elm_type, fields),
-        TypeInfo::Enum { variants, .. } => enum_type(&elm_type, variants),
+        TypeInfo::Unit => unit(&elm_type, &type_variables),
+        TypeInfo::Newtype(ty) => newtype(&elm_type, &type_variables, &ty),
+        TypeInfo::Tuple(tys) => tuple(&elm_type, &type_variables, &tys),
+        TypeInfo::Struct(fields) => struct_type(&elm_type, &type_variables, fields),
+        TypeInfo::Enum { variants, .. } => enum_type(&elm_type, &type_variables, variants),
     };
 
     for p in generics.type_params_mut() {
         p.bounds.push(syn::parse_str("::elm_rs::Elm").unwrap());
     }
 
+    let elm_type = if phantom_params.is_empty() {
+        quote! {
+            ::std::convert::From::from(#elm_type)
+        }
+    } else {
+        // the phantom type parameters are applied to the type, e.g. Id (User)
+        quote! {
+            (
+                &[
+                    ::std::string::String::from(#elm_type),
+                    #(::std::format!("({})", <#phantom_params as ::elm_rs::Elm>::elm_type())),*
+                ]
+            ).join(" ")
+        }
+    };
+
     quote! {
         impl #generics ::elm_rs::Elm for #ident #generics_without_bounds {
             fn elm_type() -> ::std::string::String {
-                ::std::convert::From::from(#elm_type)
+                #elm_type
             }
 
             fn elm_definition() -> ::std::option::Option<::std::string::String> {
@@ -51,31 +71,34 @@ fn intermediate_to_token_stream(
     }
 }
 
-fn unit(elm_type: &str) -> TokenStream2 {
+fn unit(elm_type: &str, type_variables: &str) -> TokenStream2 {
     quote! {::std::format!("\
-type {elm_type}
+type {elm_type}{type_variables}
     = {elm_type}
 ",
         elm_type = #elm_type,
+        type_variables = #type_variables,
     )}
 }
 
-fn newtype(elm_type: &str, ty: &Type) -> TokenStream2 {
+fn newtype(elm_type: &str, type_variables: &str, ty: &Type) -> TokenStream2 {
     quote! {::std::format!("\
-type {elm_type}
+type {elm_type}{type_variables}
     = {elm_type} ({inner_type})
 ",
         elm_type = #elm_type,
+        type_variables = #type_variables,
         inner_type = <#ty as ::elm_rs::Elm>::elm_type(),
     )}
 }
 
-fn tuple(elm_type: &str, ts: &[Type]) -> TokenStream2 {
+fn tuple(elm_type: &str, type_variables: &str, ts: &[Type]) -> TokenStream2 {
     quote! {::std::format!("\
-type {elm_type}
+type {elm_type}{type_variables}
     = {elm_type} {types}
 ",
         elm_type = #elm_type,
+        type_variables = #type_variables,
         types =
             (
                 &[
@@ -85,9 +108,28 @@ type {elm_type}
     )}
 }
 
-fn struct_type(elm_type: &str, fields: Vec<StructField>) -> TokenStream2 {
-    let ids = fields.iter().map(|field| field.name_elm());
-    let tys = fields.iter().map(|field| &field.ty);
+fn struct_type(elm_type: &str, type_variables: &str, fields: Vec<StructField>) -> TokenStream2 {
+    let fields = fields.iter().filter(|field| !field.phantom);
+    let ids = fields.clone().map(|field| field.name_elm());
+    let tys = fields.map(|field| &field.ty);
+    if !type_variables.is_empty() {
+        // type aliases can't have phantom type variables, so the record is wrapped in a custom type
+        return quote! {::std::format!("\
+type {elm_type}{type_variables}
+    = {elm_type}
+        {{ {fields}
+        }}
+",
+            elm_type = #elm_type,
+            type_variables = #type_variables,
+            fields =
+                (
+                    &[
+                        #(::std::format!("{} : {}", #ids, <#tys as ::elm_rs::Elm>::elm_type())),*
+                    ] as &[::std::string::String]
+                ).join("\n        , "),
+        )};
+    }
     quote! {::std::format!("\
 type alias {elm_type} =
     {{ {fields}
@@ -98,12 +140,16 @@ type alias {elm_type} =
             (
                 &[
                     #(::std::format!("{} : {}", #ids, <#tys as ::elm_rs::Elm>::elm_type())),*
-                ]
+                ] as &[::std::string::String]
             ).join("\n    , "),
     )}
 }
 
-fn enum_type(elm_type: &str, enum_variants: Vec<EnumVariant>) -> TokenStream2 {
+fn enum_type(
+    elm_type: &str,
+    type_variables: &str,
+    enum_variants: Vec<EnumVariant>,
+) -> TokenStream2 {
     let mut enum_fields: Vec<TokenStream2> = vec![];
     for enum_variant in enum_variants {
         let variant_elm_name = enum_variant.name_elm();
@@ -134,8 +180,9 @@ fn enum_type(elm_type: &str, enum_variants: Vec<EnumVariant>) -> TokenStream2 {
                 enum_fields.push(field);
             }
             EnumVariantKind::Struct(fields) => {
-                let ids = fields.iter().map(|field| field.name_elm());
-                let tys = fields.iter().map(|field| &field.ty);
+                let fields = fields.iter().filter(|field| !field.phantom);
+                let ids = fields.clone().map(|field| field.name_elm());
+                let tys = fields.map(|field| &field.ty);
                 let field = quote! {
                     ::std::format!("{name} {{ {fields} }}",
                     name = #variant_elm_name,
@@ -151,10 +198,11 @@ fn enum_type(elm_type: &str, enum_variants: Vec<EnumVariant>) -> TokenStream2 {
         }
     }
     quote! {::std::format!("\
-type {elm_type}
+type {elm_type}{type_variables}
     = {enum_fields}
 ", 
         elm_type = #elm_type,
+        type_variables = #type_variables,
         enum_fields =
             (
                 &[
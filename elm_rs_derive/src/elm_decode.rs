@@ -1,6 +1,6 @@
 //! Derive macro for ElmDecode.
 
-use super::{EnumVariantKind, Intermediate, TypeInfo};
+use super::{phantom_type_variables, EnumVariantKind, Intermediate, TypeInfo};
 #[cfg(feature = "serde")]
 use crate::attributes::serde::EnumRepresentation;
 use crate::{attributes::ContainerAttributes, EnumVariant, StructField};
@@ -31,17 +31,31 @@ fn intermediate_to_token_stream(
         generics_without_bounds,
         type_info,
         container_attributes,
+        phantom_params,
     }: Intermediate,
 ) -> syn::Result<TokenStream2> {
     let decoder_type = format!("{}Decoder", elm_type.to_lower_camel_case());
+    let type_variables = phantom_type_variables(&phantom_params);
+    let phantom = !type_variables.is_empty();
+    // the type as used in annotations, which differs from the constructor with phantom type variables
+    let annotation = if phantom {
+        format!("({elm_type}{type_variables})")
+    } else {
+        elm_type.clone()
+    };
 
     let decoder = match type_info {
-        TypeInfo::Unit => struct_unit(&elm_type, &decoder_type),
-        TypeInfo::Newtype(ty) => struct_newtype(&elm_type, &decoder_type, &ty),
-        TypeInfo::Tuple(tys) => struct_tuple(&elm_type, &decoder_type, &tys),
-        TypeInfo::Struct(fields) => {
-            struct_named(&elm_type, &decoder_type, &fields, &container_attributes)
-        }
+        TypeInfo::Unit => struct_unit(&elm_type, &annotation, &decoder_type),
+        TypeInfo::Newtype(ty) => struct_newtype(&elm_type, &annotation, &decoder_type, &ty),
+        TypeInfo::Tuple(tys) => struct_tuple(&elm_type, &annotation, &decoder_type, &tys),
+        TypeInfo::Struct(fields) => struct_named(
+            &elm_type,
+            &annotation,
+            phantom,
+            &decoder_type,
+            &fields,
+            &container_attributes,
+        ),
         TypeInfo::Enum {
             variants,
             #[cfg(feature = "serde")]
@@ -50,17 +64,17 @@ fn intermediate_to_token_stream(
             #[cfg(feature = "serde")]
             let representation = match representation {
                 EnumRepresentation::External => {
-                    enum_external(&elm_type, &decoder_type, variants, &container_attributes)
+                    enum_external(&annotation, &decoder_type, variants, &container_attributes)
                 }
                 EnumRepresentation::Internal { tag } => enum_internal(
-                    &elm_type,
+                    &annotation,
                     &decoder_type,
                     variants,
                     &tag,
                     &container_attributes,
                 )?,
                 EnumRepresentation::Adjacent { tag, content } => enum_adjacent(
-                    &elm_type,
+                    &annotation,
                     &decoder_type,
                     variants,
                     &tag,
@@ -68,12 +82,12 @@ fn intermediate_to_token_stream(
                     &container_attributes,
                 )?,
                 EnumRepresentation::Untagged => {
-                    enum_untagged(&elm_type, &decoder_type, variants, &container_attributes)?
+                    enum_untagged(&annotation, &decoder_type, variants, &container_attributes)?
                 }
             };
             #[cfg(not(feature = "serde"))]
             let representation =
-                enum_external(&elm_type, &decoder_type, variants, &container_attributes);
+                enum_external(&annotation, &decoder_type, variants, &container_attributes);
             representation
         }
     };
@@ -105,13 +119,14 @@ fn intermediate_to_token_stream(
 /// #[derive(Deserialize, Serialize)]
 /// struct Unit;
 /// "null"
-fn struct_unit(elm_type: &str, decoder_type: &str) -> TokenStream2 {
+fn struct_unit(elm_type: &str, annotation: &str, decoder_type: &str) -> TokenStream2 {
     quote! {::std::format!("\
-{decoder_type} : Json.Decode.Decoder {elm_type}
+{decoder_type} : Json.Decode.Decoder {annotation}
 {decoder_type} =
     Json.Decode.null {elm_type}
 ",
         elm_type = #elm_type,
+        annotation = #annotation,
         decoder_type = #decoder_type,
     )}
 }
@@ -119,13 +134,14 @@ fn struct_unit(elm_type: &str, decoder_type: &str) -> TokenStream2 {
 /// #[derive(Deserialize, Serialize)]
 /// struct Newtype(i32);
 /// "0"
-fn struct_newtype(elm_type: &str, decoder_type: &str, ty: &Type) -> TokenStream2 {
+fn struct_newtype(elm_type: &str, annotation: &str, decoder_type: &str, ty: &Type) -> TokenStream2 {
     quote! {::std::format!("\
-{decoder_type} : Json.Decode.Decoder {elm_type}
+{decoder_type} : Json.Decode.Decoder {annotation}
 {decoder_type} =
     Json.Decode.map {elm_type} ({inner_decoder})
 ",
         elm_type = #elm_type,
+        annotation = #annotation,
         decoder_type = #decoder_type,
         inner_decoder = <#ty>::decoder_type(),
     )}
@@ -134,15 +150,21 @@ fn struct_newtype(elm_type: &str, decoder_type: &str, ty: &Type) -> TokenStream2
 /// #[derive(Deserialize, Serialize)]
 /// struct Tuple(i32, i32);
 /// "[0,0]"
-fn struct_tuple(elm_type: &str, decoder_type: &str, inner_types: &[Type]) -> TokenStream2 {
+fn struct_tuple(
+    elm_type: &str,
+    annotation: &str,
+    decoder_type: &str,
+    inner_types: &[Type],
+) -> TokenStream2 {
     let indices: Vec<usize> = inner_types.iter().enumerate().map(|(i, _)| i).collect();
     quote! {::std::format!("\
-{decoder_type} : Json.Decode.Decoder {elm_type}
+{decoder_type} : Json.Decode.Decoder {annotation}
 {decoder_type} =
     Json.Decode.succeed {elm_type}
         {decoders}
 ",
         elm_type = #elm_type,
+        annotation = #annotation,
         decoder_type = #decoder_type,
         decoders = (
             &[
@@ -162,32 +184,63 @@ fn struct_tuple(elm_type: &str, decoder_type: &str, inner_types: &[Type]) -> Tok
 /// "{\"a\":0}"
 fn struct_named(
     elm_type: &str,
+    annotation: &str,
+    phantom: bool,
     decoder_type: &str,
     fields: &[StructField],
     container_attributes: &ContainerAttributes,
 ) -> TokenStream2 {
     let mut field_decoders = vec![];
+    let mut field_names_decode = vec![];
     for field in fields {
         let ty = &field.ty;
         let field_name_decode = field.name_decode(container_attributes);
+        if field.phantom {
+            field_names_decode.push(field_name_decode);
+            continue;
+        }
         field_decoders.push(quote!{::std::format!("|> Json.Decode.andThen (\\x -> Json.Decode.map x (Json.Decode.field \"{field_name_decode}\" ({decoder})))",
                 field_name_decode = #field_name_decode,
                 decoder = <#ty as ::elm_rs::ElmDecode>::decoder_type(),
         )});
+        field_names_decode.push(field_name_decode);
     }
+    let deny_unknown_fields =
+        deny_unknown_fields("\n        ", &field_names_decode, container_attributes);
+    // with phantom type variables the record is wrapped in a custom type
+    let constructor = if phantom {
+        let field_names = fields
+            .iter()
+            .filter(|field| !field.phantom)
+            .map(|field| field.name_elm())
+            .collect::<Vec<_>>();
+        format!(
+            "(\\{} -> {elm_type} {{ {} }})",
+            field_names.join(" "),
+            field_names
+                .iter()
+                .map(|field_name| format!("{field_name} = {field_name}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    } else {
+        elm_type.to_string()
+    };
     quote! {::std::format!("\
-{decoder_type} : Json.Decode.Decoder {elm_type}
+{decoder_type} : Json.Decode.Decoder {annotation}
 {decoder_type} =
-    Json.Decode.succeed {elm_type}
-        {field_decoders}
+    Json.Decode.succeed {constructor}
+        {field_decoders}{deny_unknown_fields}
 ",
-        elm_type = #elm_type,
+        annotation = #annotation,
+        constructor = #constructor,
         decoder_type = #decoder_type,
         field_decoders = (
             &[
                 #(#field_decoders),*
             ]
         ).join("\n        "),
+        deny_unknown_fields = #deny_unknown_fields,
     )}
 }
 
@@ -337,6 +390,7 @@ _ ->
             }
             EnumVariantKind::Struct(fields) => {
                 let (decoder, constructor) = enum_variant_struct_internal(
+                    tag,
                     &elm_name,
                     &elm_name_decode,
                     fields,
@@ -650,16 +704,24 @@ fn enum_variant_struct_external(
 ) -> (TokenStream2, TokenStream2) {
     let (field_names, tys): (Vec<_>, Vec<_>) = fields
         .iter()
+        .filter(|field| !field.phantom)
         .map(|field| (field.name_elm(), &field.ty))
         .unzip();
     let field_names_deserialize = fields
         .iter()
-        .map(|field| field.name_decode(container_attributes));
+        .filter(|field| !field.phantom)
+        .map(|field| field.name_decode(container_attributes))
+        .collect::<Vec<_>>();
+    let known_fields = fields
+        .iter()
+        .map(|field| field.name_decode(container_attributes))
+        .collect::<Vec<_>>();
+    let deny_unknown_fields = deny_unknown_fields(" ", &known_fields, container_attributes);
 
     let constructor = constructor(variant_name, &field_names);
 
     let decoder = quote! {::std::format!("\
-    Json.Decode.field \"{variant_name_decode}\" (Json.Decode.succeed elmRsConstruct{variant_name} {decoders})",
+    Json.Decode.field \"{variant_name_decode}\" (Json.Decode.succeed elmRsConstruct{variant_name} {decoders}{deny_unknown_fields})",
             variant_name = #variant_name,
             variant_name_decode = #variant_name_decode,
             decoders = (
@@ -670,6 +732,7 @@ fn enum_variant_struct_external(
                     )),*
                 ]
             ).join(" "),
+            deny_unknown_fields = #deny_unknown_fields,
     )};
 
     (decoder, constructor)
@@ -711,6 +774,7 @@ fn enum_variant_unit_internal_or_adjacent(
 /// "{\"t\":\"Struct\",\"a\":0}"
 #[cfg(feature = "serde")]
 fn enum_variant_struct_internal(
+    tag: &str,
     variant_name: &str,
     variant_name_decode: &str,
     fields: &[StructField],
@@ -718,17 +782,29 @@ fn enum_variant_struct_internal(
 ) -> (TokenStream2, TokenStream2) {
     let (field_names, tys): (Vec<_>, Vec<_>) = fields
         .iter()
+        .filter(|field| !field.phantom)
         .map(|field| (field.name_elm(), &field.ty))
         .unzip();
     let field_names_deserialize = fields
         .iter()
-        .map(|field| field.name_decode(container_attributes));
+        .filter(|field| !field.phantom)
+        .map(|field| field.name_decode(container_attributes))
+        .collect::<Vec<_>>();
+    let known_fields = fields
+        .iter()
+        .map(|field| field.name_decode(container_attributes))
+        .collect::<Vec<_>>();
+    // the tag is a part of the same object
+    let known_fields = std::iter::once(tag.to_string())
+        .chain(known_fields)
+        .collect::<Vec<_>>();
+    let deny_unknown_fields = deny_unknown_fields(" ", &known_fields, container_attributes);
 
     let constructor = constructor(variant_name, &field_names);
 
     let decoder = quote! {::std::format!("\
                     \"{variant_name_decode}\" ->
-                        Json.Decode.succeed elmRsConstruct{variant_name} {decoders}",
+                        Json.Decode.succeed elmRsConstruct{variant_name} {decoders}{deny_unknown_fields}",
             variant_name = #variant_name,
             variant_name_decode = #variant_name_decode,
             decoders = (
@@ -739,6 +815,7 @@ fn enum_variant_struct_internal(
                     )),*
                 ]
             ).join(" "),
+            deny_unknown_fields = #deny_unknown_fields,
     )};
 
     (decoder, constructor)
@@ -819,22 +896,30 @@ fn enum_variant_struct_adjacent(
 ) -> (TokenStream2, TokenStream2) {
     let field_names = fields
         .iter()
+        .filter(|field| !field.phantom)
         .map(|field| field.name_elm())
         .collect::<Vec<_>>();
     let constructor = constructor(variant_name, &field_names);
 
     let mut field_decoders = vec![];
+    let mut field_names_decode = vec![];
     for field in fields {
         let ty = &field.ty;
         let field_name_decode = field.name_decode(container_attributes);
+        if field.phantom {
+            field_names_decode.push(field_name_decode);
+            continue;
+        }
         field_decoders.push(quote!{::std::format!("|> Json.Decode.andThen (\\x -> Json.Decode.map x (Json.Decode.field \"{field_name_decode}\" ({decoder})))",
                 field_name_decode = #field_name_decode,
                 decoder = <#ty as ::elm_rs::ElmDecode>::decoder_type(),
         )});
+        field_names_decode.push(field_name_decode);
     }
+    let deny_unknown_fields = deny_unknown_fields(" ", &field_names_decode, container_attributes);
     let decoder = quote! {::std::format!("\
 \"{variant_name_decode}\" ->
-                        Json.Decode.field \"{content}\" (Json.Decode.succeed elmRsConstruct{variant_name} {field_decoders})",
+                        Json.Decode.field \"{content}\" (Json.Decode.succeed elmRsConstruct{variant_name} {field_decoders}{deny_unknown_fields})",
         variant_name = #variant_name,
         variant_name_decode = #variant_name_decode,
         content = #content,
@@ -843,6 +928,7 @@ fn enum_variant_struct_adjacent(
                 #(#field_decoders),*
             ]
         ).join("\n        "),
+        deny_unknown_fields = #deny_unknown_fields,
     )};
 
     (decoder, constructor)
@@ -918,14 +1004,22 @@ fn enum_variant_struct_untagged(
 ) -> (TokenStream2, TokenStream2) {
     let (field_names, tys): (Vec<_>, Vec<_>) = fields
         .iter()
+        .filter(|field| !field.phantom)
         .map(|field| (field.name_elm(), &field.ty))
         .unzip();
     let field_names_deserialize = fields
         .iter()
-        .map(|field| field.name_decode(container_attributes));
+        .filter(|field| !field.phantom)
+        .map(|field| field.name_decode(container_attributes))
+        .collect::<Vec<_>>();
+    let known_fields = fields
+        .iter()
+        .map(|field| field.name_decode(container_attributes))
+        .collect::<Vec<_>>();
+    let deny_unknown_fields = deny_unknown_fields(" ", &known_fields, container_attributes);
     let constructor = constructor(variant_name, &field_names);
     let decoder = quote! {::std::format!("\
-    Json.Decode.succeed elmRsConstruct{variant_name} {decoders}",
+    Json.Decode.succeed elmRsConstruct{variant_name} {decoders}{deny_unknown_fields}",
             variant_name = #variant_name,
             decoders = (
                 &[
@@ -935,6 +1029,7 @@ fn enum_variant_struct_untagged(
                     )),*
                 ]
             ).join(" "),
+            deny_unknown_fields = #deny_unknown_fields,
     )};
 
     (decoder, constructor)
@@ -965,3 +1060,35 @@ elmRsConstruct{enum_variant} {fields} =
         ).join(", "),
     )}
 }
+
+/// Generates a decoder step that fails if the object has fields other than the known ones.
+/// The step is only generated for containers with `#[serde(deny_unknown_fields)]`,
+/// or when `Settings::deny_unknown_fields` is active.
+fn deny_unknown_fields(
+    separator: &str,
+    known_fields: &[String],
+    container_attributes: &ContainerAttributes,
+) -> TokenStream2 {
+    #[cfg(feature = "serde")]
+    let deny = container_attributes.serde.deny_unknown_fields;
+    #[cfg(not(feature = "serde"))]
+    let deny = {
+        let _ = container_attributes;
+        false
+    };
+    let known_fields = known_fields
+        .iter()
+        .map(|field| format!("\"{field}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    quote! {
+        if #deny || ::elm_rs::Settings::current().deny_unknown_fields {
+            ::std::format!("{separator}|> Json.Decode.andThen (\\x -> Json.Decode.keyValuePairs Json.Decode.value |> Json.Decode.andThen (\\pairs -> List.filter (\\( key, _ ) -> not (List.member key [ {known_fields} ])) pairs |> List.head |> Maybe.map (\\( key, _ ) -> Json.Decode.fail <| \"Unexpected field \" ++ key) |> Maybe.withDefault (Json.Decode.succeed x)))",
+                separator = #separator,
+                known_fields = #known_fields,
+            )
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Derive macro for ElmEncode.
 
-use super::{EnumVariantKind, Intermediate, TypeInfo};
+use super::{phantom_type_variables, EnumVariantKind, Intermediate, TypeInfo};
 #[cfg(feature = "serde")]
 use crate::attributes::serde::EnumRepresentation;
 use crate::{attributes::ContainerAttributes, EnumVariant, StructField};
@@ -31,17 +31,31 @@ fn intermediate_to_token_stream(
         generics_without_bounds,
         type_info,
         container_attributes,
+        phantom_params,
     }: Intermediate,
 ) -> syn::Result<TokenStream2> {
     let encoder_type = format!("{}Encoder", elm_type.to_lower_camel_case());
+    let type_variables = phantom_type_variables(&phantom_params);
+    let phantom = !type_variables.is_empty();
+    // the type as used in annotations, which differs from the constructor with phantom type variables
+    let annotation = if phantom {
+        format!("({elm_type}{type_variables})")
+    } else {
+        elm_type.clone()
+    };
 
     let encoder = match type_info {
-        TypeInfo::Unit => struct_unit(&elm_type, &encoder_type),
-        TypeInfo::Newtype(ty) => struct_newtype(&elm_type, &encoder_type, &ty),
-        TypeInfo::Tuple(tys) => struct_tuple(&elm_type, &encoder_type, &tys),
-        TypeInfo::Struct(fields) => {
-            struct_named(&elm_type, &encoder_type, &fields, &container_attributes)
-        }
+        TypeInfo::Unit => struct_unit(&annotation, &encoder_type),
+        TypeInfo::Newtype(ty) => struct_newtype(&elm_type, &annotation, &encoder_type, &ty),
+        TypeInfo::Tuple(tys) => struct_tuple(&elm_type, &annotation, &encoder_type, &tys),
+        TypeInfo::Struct(fields) => struct_named(
+            &elm_type,
+            &annotation,
+            phantom,
+            &encoder_type,
+            &fields,
+            &container_attributes,
+        ),
         TypeInfo::Enum {
             variants,
             #[cfg(feature = "serde")]
@@ -50,17 +64,17 @@ fn intermediate_to_token_stream(
             #[cfg(feature = "serde")]
             let representation = match representation {
                 EnumRepresentation::External => {
-                    enum_external(&elm_type, &encoder_type, variants, &container_attributes)
+                    enum_external(&annotation, &encoder_type, variants, &container_attributes)
                 }
                 EnumRepresentation::Internal { tag } => enum_internal(
-                    &elm_type,
+                    &annotation,
                     &encoder_type,
                     variants,
                     &tag,
                     &container_attributes,
                 )?,
                 EnumRepresentation::Adjacent { tag, content } => enum_adjacent(
-                    &elm_type,
+                    &annotation,
                     &encoder_type,
                     variants,
                     &tag,
@@ -68,12 +82,12 @@ fn intermediate_to_token_stream(
                     &container_attributes,
                 )?,
                 EnumRepresentation::Untagged => {
-                    enum_untagged(&elm_type, &encoder_type, variants, &container_attributes)?
+                    enum_untagged(&annotation, &encoder_type, variants, &container_attributes)?
                 }
             };
             #[cfg(not(feature = "serde"))]
             let representation =
-                enum_external(&elm_type, &encoder_type, variants, &container_attributes);
+                enum_external(&annotation, &encoder_type, variants, &container_attributes);
             representation
         }
     };
@@ -119,13 +133,14 @@ fn struct_unit(elm_type: &str, encoder_type: &str) -> TokenStream2 {
 /// #[derive(Deserialize, Serialize)]
 /// struct Newtype(i32);
 /// "0"
-fn struct_newtype(elm_type: &str, encoder_type: &str, ty: &Type) -> TokenStream2 {
+fn struct_newtype(elm_type: &str, annotation: &str, encoder_type: &str, ty: &Type) -> TokenStream2 {
     quote! {::std::format!("\
-{encoder_type} : {elm_type} -> Json.Encode.Value
+{encoder_type} : {annotation} -> Json.Encode.Value
 {encoder_type} ({elm_type} inner) =
     ({inner_encoder}) inner
 ",
         elm_type = #elm_type,
+        annotation = #annotation,
         encoder_type = #encoder_type,
         inner_encoder = <#ty>::encoder_type(),
     )}
@@ -134,17 +149,23 @@ fn struct_newtype(elm_type: &str, encoder_type: &str, ty: &Type) -> TokenStream2
 /// #[derive(Deserialize, Serialize)]
 /// struct Tuple(i32, i32);
 /// "[0,0]"
-fn struct_tuple(elm_type: &str, encoder_type: &str, inner_types: &[Type]) -> TokenStream2 {
+fn struct_tuple(
+    elm_type: &str,
+    annotation: &str,
+    encoder_type: &str,
+    inner_types: &[Type],
+) -> TokenStream2 {
     let indices: Vec<usize> = inner_types.iter().enumerate().map(|(i, _)| i).collect();
 
     quote! {::std::format!("\
-{encoder_type} : {elm_type} -> Json.Encode.Value
+{encoder_type} : {annotation} -> Json.Encode.Value
 {encoder_type} ({elm_type} {params}) =
     Json.Encode.list identity
         [ {encoders}
         ]
 ",
         elm_type = #elm_type,
+        annotation = #annotation,
         encoder_type = #encoder_type,
         params = (&[#(::std::format!("t{idx}",
                 idx = #indices)),*
@@ -168,31 +189,31 @@ fn struct_tuple(elm_type: &str, encoder_type: &str, inner_types: &[Type]) -> Tok
 /// "{\"a\":0}"
 fn struct_named(
     elm_type: &str,
+    annotation: &str,
+    phantom: bool,
     encoder_type: &str,
     fields: &[StructField],
     container_attributes: &ContainerAttributes,
 ) -> TokenStream2 {
-    let mut field_encoders = vec![];
-    for field in fields {
-        let field_name = field.name_elm();
-        let field_name_encode = field.name_encode(container_attributes);
-        let ty = &field.ty;
-        field_encoders.push(
-            quote! {::std::format!("( \"{field_name_encode}\", ({encoder}) struct.{field_name} )",
-            field_name_encode = #field_name_encode,
-            encoder = <#ty as ::elm_rs::ElmEncode>::encoder_type(),
-            field_name = #field_name)},
-        )
-    }
+    let field_encoders = fields
+        .iter()
+        .map(|field| field_encoder(field, "struct.", container_attributes));
+    // with phantom type variables the record is wrapped in a custom type
+    let pattern = if phantom {
+        format!("({elm_type} struct)")
+    } else {
+        "struct".to_string()
+    };
     let encoder = quote! {::std::format!("\
-{encoder_type} : {elm_type} -> Json.Encode.Value
-{encoder_type} struct =
+{encoder_type} : {annotation} -> Json.Encode.Value
+{encoder_type} {pattern} =
     Json.Encode.object
         [ {fields}
         ]
 ",
-        elm_type = #elm_type,
+        annotation = #annotation,
         encoder_type = #encoder_type,
+        pattern = #pattern,
         fields = (
             &[
                 #(#field_encoders),*
@@ -541,13 +562,10 @@ fn enum_variant_struct_external(
     fields: &[StructField],
     container_attributes: &ContainerAttributes,
 ) -> TokenStream2 {
-    let (field_names, tys): (Vec<_>, Vec<_>) = fields
-        .iter()
-        .map(|field| (field.name_elm(), &field.ty))
-        .unzip();
-    let field_names_serialize = fields
+    let field_names = field_pattern(fields);
+    let encoders = fields
         .iter()
-        .map(|field| field.name_encode(container_attributes));
+        .map(|field| field_encoder(field, "", container_attributes));
 
     quote! {::std::format!("\
 {variant_name} {{ {fields} }} ->
@@ -562,11 +580,7 @@ fn enum_variant_struct_external(
         ).join(", "),
         encoders = (
             &[
-                #(::std::format!("( \"{}\", ({}) {} )",
-                    #field_names_serialize,
-                    <#tys as ::elm_rs::ElmEncode>::encoder_type(),
-                    #field_names,
-                )),*
+                #(#encoders),*
             ]
         ).join(", ")
     )}
@@ -616,13 +630,10 @@ fn enum_variant_struct_internal(
     fields: &[StructField],
     container_attributes: &ContainerAttributes,
 ) -> TokenStream2 {
-    let (field_names, tys): (Vec<_>, Vec<_>) = fields
+    let field_names = field_pattern(fields);
+    let encoders = fields
         .iter()
-        .map(|field| (field.name_elm(), &field.ty))
-        .unzip();
-    let field_names_serialize = fields
-        .iter()
-        .map(|field| field.name_encode(container_attributes));
+        .map(|field| field_encoder(field, "", container_attributes));
 
     quote! {::std::format!("\
 {variant_name} {{ {fields} }} ->
@@ -635,11 +646,7 @@ fn enum_variant_struct_internal(
         variant_name_encode = #variant_name_encode,
         encoders = (
             &[
-                #(::std::format!("( \"{}\", ({}) {} )",
-                    #field_names_serialize,
-                    <#tys>::encoder_type(),
-                    #field_names,
-                )),*
+                #(#encoders),*
             ]
         ).join(", "),
     )}
@@ -727,13 +734,10 @@ fn enum_variant_struct_adjacent(
     fields: &[StructField],
     container_attributes: &ContainerAttributes,
 ) -> TokenStream2 {
-    let (field_names, tys): (Vec<_>, Vec<_>) = fields
-        .iter()
-        .map(|field| (field.name_elm(), &field.ty))
-        .unzip();
-    let field_names_serialize = fields
+    let field_names = field_pattern(fields);
+    let encoders = fields
         .iter()
-        .map(|field| field.name_encode(container_attributes));
+        .map(|field| field_encoder(field, "", container_attributes));
 
     quote! { format!("\
     {variant_name} {{ {fields} }} ->
@@ -749,11 +753,7 @@ fn enum_variant_struct_adjacent(
         variant_name_encode = #variant_name_encode,
         encoders = (
             &[
-                #(::std::format!("( \"{}\", ({}) {} )",
-                    #field_names_serialize,
-                    <#tys as ::elm_rs::ElmEncode>::encoder_type(),
-                    #field_names,
-                )),*
+                #(#encoders),*
             ]
         ).join(", "),
     )}
@@ -841,13 +841,10 @@ fn enum_variant_struct_untagged(
     fields: &[StructField],
     container_attributes: &ContainerAttributes,
 ) -> TokenStream2 {
-    let (field_names, tys): (Vec<_>, Vec<_>) = fields
-        .iter()
-        .map(|field| (field.name_elm(), &field.ty))
-        .unzip();
-    let field_names_serialize = fields
+    let field_names = field_pattern(fields);
+    let encoders = fields
         .iter()
-        .map(|field| field.name_encode(container_attributes));
+        .map(|field| field_encoder(field, "", container_attributes));
     quote! {::std::format!("\
 {variant_name} {{ {fields} }} ->
             Json.Encode.object [ {encoders} ]",
@@ -860,12 +857,43 @@ fn enum_variant_struct_untagged(
         ).join(", "),
         encoders = (
             &[
-                #(::std::format!("( \"{}\", ({}) {} )",
-                    #field_names_serialize,
-                    <#tys as ::elm_rs::ElmEncode>::encoder_type(),
-                    #field_names,
-                )),*
+                #(#encoders),*
             ]
         ).join(", ")
     )}
 }
+
+// #######
+// helpers
+// #######
+
+/// Encodes a struct field as a key-value pair, PhantomData fields are encoded as null.
+fn field_encoder(
+    field: &StructField,
+    prefix: &str,
+    container_attributes: &ContainerAttributes,
+) -> TokenStream2 {
+    let field_name = field.name_elm();
+    let field_name_encode = field.name_encode(container_attributes);
+    if field.phantom {
+        return quote! {::std::format!("( \"{field_name_encode}\", Json.Encode.null )",
+            field_name_encode = #field_name_encode,
+        )};
+    }
+    let ty = &field.ty;
+    quote! {::std::format!("( \"{field_name_encode}\", ({encoder}) {prefix}{field_name} )",
+        field_name_encode = #field_name_encode,
+        encoder = <#ty as ::elm_rs::ElmEncode>::encoder_type(),
+        prefix = #prefix,
+        field_name = #field_name,
+    )}
+}
+
+/// The names of the fields bound in a record pattern, PhantomData fields are not a part of the record.
+fn field_pattern(fields: &[StructField]) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|field| !field.phantom)
+        .map(|field| field.name_elm())
+        .collect()
+}
@@ -0,0 +1,144 @@
+//! Derive macro for ElmForm.
+
+use crate::{phantom_type_variables, Intermediate, TypeInfo};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    let intermediate = match Intermediate::parse(derive_input) {
+        Ok(intermediate) => intermediate,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let token_stream = match intermediate_to_token_stream(intermediate) {
+        Ok(token_stream) => token_stream,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    TokenStream::from(token_stream)
+}
+
+fn intermediate_to_token_stream(
+    Intermediate {
+        ident,
+        elm_type,
+        mut generics,
+        generics_without_bounds,
+        type_info,
+        container_attributes,
+        phantom_params,
+    }: Intermediate,
+) -> syn::Result<TokenStream2> {
+    let type_variables = phantom_type_variables(&phantom_params);
+    let url_encoded_body_type = format!("urlEncodedBody{elm_type}");
+    let multipart_body_type = format!("multipartBody{elm_type}");
+    // with phantom type variables the record is wrapped in a custom type
+    let (annotation, pattern) = if type_variables.is_empty() {
+        (elm_type.clone(), "struct".to_string())
+    } else {
+        (
+            format!("({elm_type}{type_variables})"),
+            format!("({elm_type} struct)"),
+        )
+    };
+    let (fields, has_files) = match type_info {
+        TypeInfo::Struct(fields) => {
+            let mut form_fields = vec![];
+            let mut field_types = vec![];
+            for field in fields.iter().filter(|field| !field.phantom) {
+                if field.flatten() || field.brackets() {
+                    return Err(syn::Error::new(
+                        field.ident.span(),
+                        "nested structs are not supported by ElmForm",
+                    ));
+                }
+                let ty = &field.ty;
+                field_types.push(ty);
+                let field_value = format!("struct.{}", field.name_elm());
+                let field_name_encode = field.name_encode(&container_attributes);
+                let comma_separated = field.comma_separated();
+                let default = field.default(&container_attributes);
+                let query_style_check = field.query_style_check();
+                form_fields.push(quote! {
+                    (
+                        {
+                            #query_style_check
+                            ::elm_rs::QueryField::new::<#ty>(
+                                #field_name_encode,
+                                #comma_separated,
+                                #default,
+                            )
+                        },
+                        ::std::borrow::ToOwned::to_owned(#field_value),
+                    )
+                });
+            }
+            (
+                quote! {
+                    ::std::vec![#(#form_fields),*]
+                },
+                quote! {
+                    false #(|| <#field_types as ::elm_rs::ElmQueryField>::query_field_file())*
+                },
+            )
+        }
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "only structs with named fields are allowed",
+            ))
+        }
+    };
+
+    for p in generics.type_params_mut() {
+        p.bounds.push(syn::parse_str("::elm_rs::Elm").unwrap());
+        p.bounds
+            .push(syn::parse_str("::elm_rs::ElmQueryField").unwrap());
+    }
+
+    let res = quote! {
+        impl #generics ::elm_rs::ElmForm for #ident #generics_without_bounds {
+            fn elm_form() -> ::std::string::String {
+                let fields: ::std::vec::Vec<(::elm_rs::QueryField, ::std::string::String)> = #fields;
+                let multipart = ::std::format!("\
+{multipart_body_type} : {annotation} -> Http.Body
+{multipart_body_type} {pattern} =
+    {body}
+",
+                    multipart_body_type = #multipart_body_type,
+                    annotation = #annotation,
+                    pattern = #pattern,
+                    body = ::elm_rs::multipart_body(&fields),
+                );
+                // files can't be sent in url encoded forms
+                if fields.iter().any(|(field, _)| field.file()) {
+                    multipart
+                } else {
+                    ::std::format!("\
+{url_encoded_body_type} : {annotation} -> Http.Body
+{url_encoded_body_type} {pattern} =
+    {body}
+
+
+{multipart}",
+                        url_encoded_body_type = #url_encoded_body_type,
+                        annotation = #annotation,
+                        pattern = #pattern,
+                        body = ::elm_rs::url_encoded_body(&fields),
+                        multipart = multipart,
+                    )
+                }
+            }
+
+            fn form_has_files() -> ::std::primitive::bool {
+                #has_files
+            }
+
+            fn url_encoded_body_type() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#url_encoded_body_type)
+            }
+        }
+    };
+    Ok(res)
+}
@@ -0,0 +1,135 @@
+//! Derive macro for ElmMapKey.
+
+use crate::{EnumVariantKind, Intermediate, TypeInfo};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    let intermediate = match Intermediate::parse(derive_input) {
+        Ok(intermediate) => intermediate,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let token_stream = match intermediate_to_token_stream(intermediate) {
+        Ok(token_stream) => token_stream,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    TokenStream::from(token_stream)
+}
+
+fn intermediate_to_token_stream(
+    Intermediate {
+        ident,
+        elm_type,
+        mut generics,
+        generics_without_bounds,
+        type_info,
+        container_attributes,
+        phantom_params: _,
+    }: Intermediate,
+) -> syn::Result<TokenStream2> {
+    // custom types are not comparable in Elm, so maps with these keys are always association lists
+    let (key_encoder, key_decoder) = match type_info {
+        // the key is serialized as the variant name
+        TypeInfo::Enum { variants, .. } => {
+            let mut names = Vec::new();
+            for variant in variants {
+                #[cfg(feature = "serde")]
+                if variant.serde_attributes.skip {
+                    continue;
+                }
+
+                if let EnumVariantKind::Unit = variant.variant {
+                    names.push((
+                        variant.name_elm().to_string(),
+                        variant.name_encode(&container_attributes).to_string(),
+                        variant.name_decode(&container_attributes).to_string(),
+                    ));
+                } else {
+                    return Err(syn::Error::new(
+                        variant.span,
+                        "only unit variants are allowed",
+                    ));
+                }
+            }
+            let ((_, last_name_encode, _), rest) = match names.split_last() {
+                Some(split) => split,
+                None => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "enums without variants are not allowed",
+                    ))
+                }
+            };
+
+            // custom types can't be pattern matched on a single line, so the variants are compared instead
+            let mut key_encoder = String::from("(\\elmRsKey -> ");
+            for (elm_name, name_encode, _) in rest {
+                key_encoder.push_str(&format!(
+                    "if elmRsKey == {elm_name} then \"{name_encode}\" else "
+                ));
+            }
+            key_encoder.push_str(&format!("\"{last_name_encode}\")"));
+
+            let mut key_decoder = String::from("(\\elmRsKey -> ");
+            for (elm_name, _, name_decode) in &names {
+                key_decoder.push_str(&format!(
+                    "if elmRsKey == \"{name_decode}\" then Just {elm_name} else "
+                ));
+            }
+            key_decoder.push_str("Nothing)");
+
+            (quote! { #key_encoder }, quote! { #key_decoder })
+        }
+        // the key is serialized as the inner value
+        TypeInfo::Newtype(ty) => {
+            let inner = format!("elmRsInner{elm_type}");
+            (
+                quote! {
+                    ::std::format!("(\\({elm_type} {inner}) -> ({key_encoder}) {inner})",
+                        elm_type = #elm_type,
+                        inner = #inner,
+                        key_encoder = <#ty as ::elm_rs::ElmMapKey>::key_encoder(),
+                    )
+                },
+                quote! {
+                    ::std::format!("(({key_decoder}) >> Maybe.map {elm_type})",
+                        elm_type = #elm_type,
+                        key_decoder = <#ty as ::elm_rs::ElmMapKey>::key_decoder(),
+                    )
+                },
+            )
+        }
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "only newtypes and enums with unit variants are allowed",
+            ))
+        }
+    };
+
+    for p in generics.type_params_mut() {
+        p.bounds.push(syn::parse_str("::elm_rs::Elm").unwrap());
+        p.bounds
+            .push(syn::parse_str("::elm_rs::ElmMapKey").unwrap());
+    }
+
+    let res = quote! {
+        impl #generics ::elm_rs::ElmMapKey for #ident #generics_without_bounds {
+            fn comparable() -> ::std::primitive::bool {
+                false
+            }
+
+            fn key_encoder() -> ::std::string::String {
+                ::std::convert::From::from(#key_encoder)
+            }
+
+            fn key_decoder() -> ::std::string::String {
+                ::std::convert::From::from(#key_decoder)
+            }
+        }
+    };
+    Ok(res)
+}
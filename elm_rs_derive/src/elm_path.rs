@@ -0,0 +1,193 @@
+//! Derive macro for ElmPath.
+
+use crate::{Intermediate, TypeInfo};
+use heck::ToLowerCamelCase;
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    let intermediate = match Intermediate::parse(derive_input) {
+        Ok(intermediate) => intermediate,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let token_stream = match intermediate_to_token_stream(intermediate) {
+        Ok(token_stream) => token_stream,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    TokenStream::from(token_stream)
+}
+
+fn intermediate_to_token_stream(
+    Intermediate {
+        ident,
+        elm_type,
+        mut generics,
+        generics_without_bounds,
+        type_info,
+        container_attributes,
+        phantom_params: _,
+    }: Intermediate,
+) -> syn::Result<TokenStream2> {
+    let path = container_attributes.elm.path.as_ref().ok_or_else(|| {
+        syn::Error::new(
+            ident.span(),
+            "ElmPath requires a path template such as #[elm(path = \"/users/{id}\")]",
+        )
+    })?;
+    let fields = match &type_info {
+        TypeInfo::Struct(fields) => fields,
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "only structs with named fields are allowed",
+            ))
+        }
+    };
+    let fields = fields
+        .iter()
+        .filter(|field| !field.phantom)
+        .collect::<Vec<_>>();
+    let path_builder_type = format!("{}Path", elm_type.to_lower_camel_case());
+
+    let mut used_fields = Vec::new();
+    let mut segment_values = Vec::new();
+    for segment in parse_path_template(path)? {
+        match segment {
+            PathSegment::Parameter(name) => {
+                let field = fields
+                    .iter()
+                    .find(|field| field.name_encode(&container_attributes) == name)
+                    .ok_or_else(|| {
+                        syn::Error::new(
+                            path.span(),
+                            format!("no field for the path parameter `{name}`"),
+                        )
+                    })?;
+                let ty = &field.ty;
+                let field_name = field.name_elm();
+                used_fields.push(field.ident.clone());
+                segment_values.push(quote! {{
+                    ::elm_rs::QueryField::check_path_parameter::<#ty>(#name);
+                    ::std::format!(
+                        "Url.percentEncode ({} struct.{})",
+                        <#ty as ::elm_rs::ElmQueryField>::query_field_encoder(),
+                        #field_name,
+                    )
+                }});
+            }
+            PathSegment::Literal(segment) => {
+                let segment = format!("{segment:?}");
+                segment_values.push(quote! { ::std::borrow::ToOwned::to_owned(#segment) });
+            }
+        }
+    }
+
+    let mut query = None;
+    for field in &fields {
+        if field.query {
+            if query.is_some() {
+                return Err(syn::Error::new(
+                    field.ident.span(),
+                    "only one field can be the query",
+                ));
+            }
+            let ty = &field.ty;
+            let field_name = field.name_elm();
+            query = Some(quote! {
+                ::std::format!(
+                    "({} struct.{})",
+                    <#ty as ::elm_rs::ElmQuery>::query_encoder_type(),
+                    #field_name,
+                )
+            });
+        } else if !used_fields.contains(&field.ident) {
+            return Err(syn::Error::new(
+                field.ident.span(),
+                "the field is not used in the path, use #[elm(query)] for the query",
+            ));
+        }
+    }
+    let query = query.unwrap_or_else(|| quote! { ::std::borrow::ToOwned::to_owned("[]") });
+
+    // the function takes a record so that it works with the type alias of the struct as well
+    let record_fields = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let field_name = field.name_elm();
+        quote! {
+            ::std::format!("{} : {}", #field_name, <#ty as ::elm_rs::Elm>::elm_type())
+        }
+    });
+
+    for p in generics.type_params_mut() {
+        p.bounds.push(syn::parse_str("::elm_rs::Elm").unwrap());
+        p.bounds
+            .push(syn::parse_str("::elm_rs::ElmQueryField").unwrap());
+    }
+
+    let res = quote! {
+        impl #generics ::elm_rs::ElmPath for #ident #generics_without_bounds {
+            fn elm_path() -> ::std::string::String {
+                ::std::format!("\
+{path_builder_type} : {record} -> String
+{path_builder_type} struct =
+    Url.Builder.absolute [ {segments} ] {query}
+",
+                    path_builder_type = #path_builder_type,
+                    record = <Self as ::elm_rs::ElmPath>::path_parameters_type(),
+                    segments = [#(#segment_values),*].join(", "),
+                    query = #query,
+                )
+            }
+
+            fn path_builder_type() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#path_builder_type)
+            }
+
+            fn path_parameters_type() -> ::std::string::String {
+                let record_fields: &[::std::string::String] = &[#(#record_fields),*];
+                if record_fields.is_empty() {
+                    ::std::borrow::ToOwned::to_owned("{}")
+                } else {
+                    ::std::format!("{{ {} }}", record_fields.join(", "))
+                }
+            }
+        }
+    };
+    Ok(res)
+}
+
+/// A segment of a path template.
+pub enum PathSegment {
+    // used as is
+    Literal(String),
+    // replaced by the field with the name, written as `{name}` or `:name`
+    Parameter(String),
+}
+
+/// Parses a path template such as `/users/{id}` or `/users/:id`.
+pub fn parse_path_template(path: &LitStr) -> syn::Result<Vec<PathSegment>> {
+    let value = path.value();
+    let segments = value
+        .strip_prefix('/')
+        .ok_or_else(|| syn::Error::new(path.span(), "the path needs to start with a `/`"))?;
+    segments
+        .split('/')
+        .map(|segment| {
+            let parameter = segment
+                .strip_prefix('{')
+                .and_then(|segment| segment.strip_suffix('}'))
+                .or_else(|| segment.strip_prefix(':'));
+            match parameter {
+                Some(name) => Ok(PathSegment::Parameter(name.to_string())),
+                None if segment.contains(['{', '}']) => Err(syn::Error::new(
+                    path.span(),
+                    format!("path parameters need to be whole segments, found `{segment}`"),
+                )),
+                None => Ok(PathSegment::Literal(segment.to_string())),
+            }
+        })
+        .collect()
+}
@@ -1,6 +1,8 @@
 //! Derive macro for ElmQuery.
 
-use crate::{Intermediate, TypeInfo};
+#[cfg(feature = "serde")]
+use crate::{attributes::serde::EnumRepresentation, EnumVariantKind};
+use crate::{phantom_type_variables, Intermediate, TypeInfo};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
@@ -27,42 +29,205 @@ fn intermediate_to_token_stream(
         generics_without_bounds,
         type_info,
         container_attributes,
+        phantom_params,
     }: Intermediate,
 ) -> syn::Result<TokenStream2> {
-    let ts = match type_info {
+    let type_variables = phantom_type_variables(&phantom_params);
+    let query_encoder_type = format!("urlEncode{elm_type}");
+    // with phantom type variables the record is wrapped in a custom type
+    let (annotation, pattern, record) = if type_variables.is_empty() {
+        (
+            elm_type.clone(),
+            "struct".to_string(),
+            quote! { value.to_string() },
+        )
+    } else {
+        (
+            format!("({elm_type}{type_variables})"),
+            format!("({elm_type} struct)"),
+            quote! {
+                ::std::format!("((\\({elm_type} elmRsRecord) -> elmRsRecord) {value})",
+                    elm_type = #elm_type,
+                    value = value,
+                )
+            },
+        )
+    };
+    let (elm_query, query_fields) = match type_info {
         TypeInfo::Struct(fields) => {
             let mut query_fields = vec![];
-            for field in fields {
+            for field in fields.iter().filter(|field| !field.phantom) {
                 let ty = &field.ty;
                 let field_name = field.name_elm();
                 let field_name_encode = field.name_encode(&container_attributes);
-                query_fields.push(quote! {::std::format!("\
-                {field_type} \"{field_name_encode}\" ({query_field_encoder} {field_value})",
-                    field_type = <#ty as ::elm_rs::ElmQueryField>::query_field_type(),
-                    query_field_encoder = <#ty as ::elm_rs::ElmQueryField>::query_field_encoder_name(),
-                    field_name_encode = #field_name_encode,
-                    field_value = ::std::format!("struct.{field_name}",
-                        field_name = #field_name
-                    ),
-                )});
+                let field_value = quote! { ::std::format!("{}.{}", record, #field_name) };
+                if field.flatten() {
+                    query_fields.push(quote! {
+                        let flattened_value = #field_value;
+                        fields.push((
+                            ::elm_rs::QueryField::flattened::<#ty>(&flattened_value),
+                            flattened_value,
+                        ));
+                    });
+                } else if field.brackets() {
+                    query_fields.push(quote! {
+                        fields.extend(::elm_rs::QueryField::nest(
+                            <#ty as ::elm_rs::ElmQuery>::query_fields(&#field_value),
+                            #field_name_encode,
+                        ));
+                    });
+                } else {
+                    let comma_separated = field.comma_separated();
+                    let default = field.default(&container_attributes);
+                    let query_style_check = field.query_style_check();
+                    query_fields.push(quote! {
+                        #query_style_check
+                        fields.push((
+                            ::elm_rs::QueryField::new::<#ty>(
+                                #field_name_encode,
+                                #comma_separated,
+                                #default,
+                            ),
+                            #field_value,
+                        ));
+                    });
+                }
+            }
+            let fields = quote! {{
+                let mut fields = ::std::vec::Vec::new();
+                #(#query_fields)*
+                fields
+            }};
+            let elm_query = quote! {
+                let record = "struct";
+                ::std::format!("\
+{query_encoder_type} : {annotation} -> List Url.Builder.QueryParameter
+{query_encoder_type} {pattern} =
+    {parameters}
+",
+                    query_encoder_type = #query_encoder_type,
+                    annotation = #annotation,
+                    pattern = #pattern,
+                    parameters = ::elm_rs::query_parameters(&#fields)
+                )
+            };
+            let query_fields = quote! {
+                let record = #record;
+                #fields
+            };
+            (elm_query, query_fields)
+        }
+        #[cfg(feature = "serde")]
+        TypeInfo::Enum {
+            variants,
+            representation,
+        } => {
+            let tag = match representation {
+                EnumRepresentation::Untagged => None,
+                EnumRepresentation::Internal { tag } => Some(tag),
+                _ => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "only untagged and internally tagged enums are allowed",
+                    ))
+                }
+            };
+            let mut branches = vec![];
+            for variant in &variants {
+                let elm_name = variant.name_elm();
+                let mut query_fields = vec![];
+                if let Some(tag) = &tag {
+                    let elm_name_encode =
+                        format!("\"{}\"", variant.name_encode(&container_attributes));
+                    query_fields.push(quote! {
+                        fields.push((
+                            ::elm_rs::QueryField::new::<::std::primitive::str>(#tag, false, false),
+                            ::std::string::String::from(#elm_name_encode),
+                        ));
+                    });
+                }
+                let pattern = match &variant.variant {
+                    EnumVariantKind::Unit if tag.is_some() => elm_name.to_string(),
+                    EnumVariantKind::Struct(fields) => {
+                        for field in fields.iter().filter(|field| !field.phantom) {
+                            let ty = &field.ty;
+                            let field_value = format!("struct.{}", field.name_elm());
+                            if field.flatten() {
+                                query_fields.push(quote! {
+                                    fields.push((
+                                        ::elm_rs::QueryField::flattened::<#ty>(#field_value),
+                                        ::std::string::String::from(#field_value),
+                                    ));
+                                });
+                                continue;
+                            }
+                            if field.brackets() {
+                                return Err(syn::Error::new(
+                                    field.ident.span(),
+                                    "the brackets query style is not supported in enum variants",
+                                ));
+                            }
+                            let field_name_encode =
+                                variant.field_name_encode(field, &container_attributes);
+                            let comma_separated = field.comma_separated();
+                            let default = field.default(&container_attributes);
+                            let query_style_check = field.query_style_check();
+                            query_fields.push(quote! {
+                                #query_style_check
+                                fields.push((
+                                    ::elm_rs::QueryField::new::<#ty>(
+                                        #field_name_encode,
+                                        #comma_separated,
+                                        #default,
+                                    ),
+                                    ::std::string::String::from(#field_value),
+                                ));
+                            });
+                        }
+                        format!("{elm_name} struct")
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            variant.span,
+                            "only struct variants and unit variants of internally tagged enums are allowed",
+                        ))
+                    }
+                };
+                // the parameters are indented under the branch
+                branches.push(quote! {{
+                    let mut fields = ::std::vec::Vec::new();
+                    #(#query_fields)*
+                    ::std::format!(
+                        "{pattern} ->\n            {parameters}",
+                        pattern = #pattern,
+                        parameters = ::elm_rs::query_parameters(&fields).replace('\n', "\n        "),
+                    )
+                }});
             }
-            quote! {::std::format!("\
-urlEncode{elm_type} : {elm_type} -> List Url.Builder.QueryParameter
-urlEncode{elm_type} struct =
-    [ {fields} ]
+            let elm_query = quote! {
+                ::std::format!("\
+{query_encoder_type} : {annotation} -> List Url.Builder.QueryParameter
+{query_encoder_type} enum =
+    case enum of
+        {branches}
 ",
-                elm_type = #elm_type,
-                fields = (
-                    &[
-                        #(#query_fields),*
-                    ]
-                ).join(", ")
-            )}
+                    query_encoder_type = #query_encoder_type,
+                    annotation = #annotation,
+                    branches = [#(#branches),*].join("\n\n        "),
+                )
+            };
+            let query_fields = quote! {
+                ::std::vec![(
+                    ::elm_rs::QueryField::enumeration::<Self>(),
+                    value.to_string(),
+                )]
+            };
+            (elm_query, query_fields)
         }
         _ => {
             return Err(syn::Error::new(
                 ident.span(),
-                "only structs with named fields are allowed",
+                "only structs with named fields and enums with struct variants are allowed",
             ))
         }
     };
@@ -76,7 +241,17 @@ urlEncode{elm_type} struct =
     let res = quote! {
         impl #generics ::elm_rs::ElmQuery for #ident #generics_without_bounds {
             fn elm_query() -> ::std::string::String {
-                #ts
+                #elm_query
+            }
+
+            fn query_encoder_type() -> ::std::string::String {
+                ::std::string::String::from(#query_encoder_type)
+            }
+
+            fn query_fields(
+                value: &::std::primitive::str,
+            ) -> ::std::vec::Vec<(::elm_rs::QueryField, ::std::string::String)> {
+                #query_fields
             }
         }
     };
@@ -27,17 +27,22 @@ fn intermediate_to_token_stream(
         generics_without_bounds,
         type_info,
         container_attributes,
+        phantom_params: _,
     }: Intermediate,
 ) -> syn::Result<TokenStream2> {
-    let query_field_encoder_name = format!("queryFieldEncoder{elm_type}");
-    let ts = match type_info {
+    let (query_field_encoder, query_field_encoder_definition, query_field_parser) = match type_info
+    {
         TypeInfo::Enum { variants, .. } => {
             let mut branches = Vec::new();
+            let mut query_field_parser = String::from("(\\elmRsValue -> ");
             for variant in variants {
                 if let EnumVariantKind::Unit = variant.variant {
                     let elm_name = variant.name_elm();
                     let name_encode = variant.name_encode(&container_attributes);
                     branches.push(format!("{elm_name} -> \"{name_encode}\""));
+                    query_field_parser.push_str(&format!(
+                        "if elmRsValue == \"{name_encode}\" then Just {elm_name} else "
+                    ));
                 } else {
                     return Err(syn::Error::new(
                         variant.span,
@@ -46,7 +51,10 @@ fn intermediate_to_token_stream(
                 }
             }
 
-            quote! {::std::format!("\
+            query_field_parser.push_str("Nothing)");
+
+            let query_field_encoder_name = format!("queryFieldEncoder{elm_type}");
+            let definition = quote! {::std::format!("\
 {function_name} : {elm_type} -> String
 {function_name} var =
     case var of
@@ -59,9 +67,39 @@ fn intermediate_to_token_stream(
                         #(#branches),*
                     ]
                 ).join("\n        ")
-            )}
+            )};
+            (
+                quote! { ::std::string::String::from(#query_field_encoder_name) },
+                quote! { ::std::option::Option::Some(#definition) },
+                quote! { ::std::string::String::from(#query_field_parser) },
+            )
+        }
+        // newtypes and transparent structs are serialized as the inner value
+        TypeInfo::Newtype(ty) => {
+            let inner = format!("elmRsInner{elm_type}");
+            (
+                quote! {
+                    ::std::format!("(\\({elm_type} {inner}) -> {query_field_encoder} {inner})",
+                        elm_type = #elm_type,
+                        inner = #inner,
+                        query_field_encoder = <#ty as ::elm_rs::ElmQueryField>::query_field_encoder(),
+                    )
+                },
+                quote! { <#ty as ::elm_rs::ElmQueryField>::query_field_encoder_definition() },
+                quote! {
+                    ::std::format!("(({query_field_parser}) >> Maybe.map {elm_type})",
+                        elm_type = #elm_type,
+                        query_field_parser = <#ty as ::elm_rs::ElmQueryField>::query_field_parser(),
+                    )
+                },
+            )
+        }
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "only newtypes and enums with unit variants are allowed",
+            ))
         }
-        _ => return Err(syn::Error::new(ident.span(), "only enums are allowed")),
     };
 
     for p in generics.type_params_mut() {
@@ -72,16 +110,16 @@ fn intermediate_to_token_stream(
 
     let res = quote! {
         impl #generics ::elm_rs::ElmQueryField for #ident #generics_without_bounds {
-            fn query_field_type() -> &'static str {
-                "Url.Builder.string"
+            fn query_field_encoder() -> ::std::string::String {
+                #query_field_encoder
             }
 
-            fn query_field_encoder_name() -> &'static str {
-                #query_field_encoder_name
+            fn query_field_encoder_definition() -> ::std::option::Option<::std::string::String> {
+                #query_field_encoder_definition
             }
 
-            fn query_field_encoder_definition() -> Option<String> {
-                Some(#ts)
+            fn query_field_parser() -> ::std::string::String {
+                #query_field_parser
             }
         }
     };
@@ -0,0 +1,155 @@
+//! Derive macro for ElmQueryParser.
+
+use crate::{phantom_type_variables, EnumVariantKind, Intermediate, TypeInfo};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    let intermediate = match Intermediate::parse(derive_input) {
+        Ok(intermediate) => intermediate,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let token_stream = match intermediate_to_token_stream(intermediate) {
+        Ok(token_stream) => token_stream,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    TokenStream::from(token_stream)
+}
+
+fn intermediate_to_token_stream(
+    Intermediate {
+        ident,
+        elm_type,
+        mut generics,
+        generics_without_bounds,
+        type_info,
+        container_attributes,
+        phantom_params,
+    }: Intermediate,
+) -> syn::Result<TokenStream2> {
+    let type_variables = phantom_type_variables(&phantom_params);
+    let phantom = !type_variables.is_empty();
+    // the type as used in annotations, which differs from the constructor with phantom type variables
+    let annotation = if phantom {
+        format!("({elm_type}{type_variables})")
+    } else {
+        elm_type.clone()
+    };
+    let query_parser_type = format!("queryParser{elm_type}");
+    let ts = match type_info {
+        TypeInfo::Struct(fields) => {
+            let mut query_fields = vec![];
+            for field in fields.iter().filter(|field| !field.phantom) {
+                let ty = &field.ty;
+                if field.flatten() {
+                    query_fields.push(quote! {
+                        ::elm_rs::QueryField::flattened_parser::<#ty>()
+                    });
+                    continue;
+                }
+                if field.brackets() {
+                    return Err(syn::Error::new(
+                        field.ident.span(),
+                        "the brackets query style is not supported by ElmQueryParser",
+                    ));
+                }
+                // parses the parameters created by ElmQuery
+                let field_name_encode = field.name_encode(&container_attributes);
+                let comma_separated = field.comma_separated();
+                let default = field.default(&container_attributes);
+                let query_style_check = field.query_style_check();
+                query_fields.push(quote! {{
+                    #query_style_check
+                    ::elm_rs::QueryField::new::<#ty>(
+                        #field_name_encode,
+                        #comma_separated,
+                        #default,
+                    )
+                }});
+            }
+            // with phantom type variables the record is wrapped in a custom type
+            let constructor = if phantom {
+                let field_names = fields
+                    .iter()
+                    .filter(|field| !field.phantom)
+                    .map(|field| field.name_elm())
+                    .collect::<Vec<_>>();
+                format!(
+                    "(\\{} -> {elm_type} {{ {} }})",
+                    field_names.join(" "),
+                    field_names
+                        .iter()
+                        .map(|field_name| format!("{field_name} = {field_name}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            } else {
+                elm_type.clone()
+            };
+            quote! {::std::format!("\
+{query_parser_type} : Url.Parser.Query.Parser (Maybe {annotation})
+{query_parser_type} =
+    {parser}
+",
+                query_parser_type = #query_parser_type,
+                annotation = #annotation,
+                parser = ::elm_rs::query_parser(#constructor, &[
+                    #(#query_fields),*
+                ])
+            )}
+        }
+        TypeInfo::Enum { variants, .. } => {
+            let mut entries = Vec::new();
+            for variant in variants {
+                if let EnumVariantKind::Unit = variant.variant {
+                    let elm_name = variant.name_elm();
+                    let name_encode = variant.name_encode(&container_attributes);
+                    entries.push(format!("( \"{name_encode}\", {elm_name} )"));
+                } else {
+                    return Err(syn::Error::new(
+                        variant.span,
+                        "only unit variants are allowed",
+                    ));
+                }
+            }
+            let entries = entries.join(", ");
+            quote! {::std::format!("\
+{query_parser_type} : String -> Url.Parser.Query.Parser (Maybe {annotation})
+{query_parser_type} elmRsKey =
+    Url.Parser.Query.enum elmRsKey (Dict.fromList [ {entries} ])
+",
+                query_parser_type = #query_parser_type,
+                annotation = #annotation,
+                entries = #entries,
+            )}
+        }
+        _ => {
+            return Err(syn::Error::new(
+                ident.span(),
+                "only structs with named fields and enums with unit variants are allowed",
+            ))
+        }
+    };
+
+    for p in generics.type_params_mut() {
+        p.bounds.push(syn::parse_str("::elm_rs::Elm").unwrap());
+        p.bounds
+            .push(syn::parse_str("::elm_rs::ElmQueryField").unwrap());
+    }
+
+    let res = quote! {
+        impl #generics ::elm_rs::ElmQueryParser for #ident #generics_without_bounds {
+            fn elm_query_parser() -> ::std::string::String {
+                #ts
+            }
+
+            fn query_parser_type() -> ::std::string::String {
+                ::std::string::String::from(#query_parser_type)
+            }
+        }
+    };
+    Ok(res)
+}
@@ -0,0 +1,222 @@
+//! Derive macro for ElmRoute.
+
+use crate::{
+    elm_path::{parse_path_template, PathSegment},
+    phantom_type_variables, EnumVariantKind, Intermediate, TypeInfo,
+};
+use heck::{ToLowerCamelCase, ToPascalCase};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    let intermediate = match Intermediate::parse(derive_input) {
+        Ok(intermediate) => intermediate,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let token_stream = match intermediate_to_token_stream(intermediate) {
+        Ok(token_stream) => token_stream,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    TokenStream::from(token_stream)
+}
+
+fn intermediate_to_token_stream(
+    Intermediate {
+        ident,
+        elm_type,
+        mut generics,
+        generics_without_bounds,
+        type_info,
+        container_attributes,
+        phantom_params,
+    }: Intermediate,
+) -> syn::Result<TokenStream2> {
+    let type_variables = phantom_type_variables(&phantom_params);
+    let annotation = if type_variables.is_empty() {
+        elm_type.clone()
+    } else {
+        format!("({elm_type}{type_variables})")
+    };
+    let route_parser_type = format!("{}Parser", elm_type.to_lower_camel_case());
+    let route_to_string_type = format!("{}ToString", elm_type.to_lower_camel_case());
+    let variants = match type_info {
+        TypeInfo::Enum { variants, .. } => variants,
+        _ => return Err(syn::Error::new(ident.span(), "only enums are allowed")),
+    };
+
+    let mut parsers = Vec::new();
+    let mut branches = Vec::new();
+    for variant in &variants {
+        let path = variant.path.as_ref().ok_or_else(|| {
+            syn::Error::new(
+                variant.span,
+                "routes require a path template such as #[elm(path = \"/users/:id\")]",
+            )
+        })?;
+        let fields = match &variant.variant {
+            EnumVariantKind::Unit => Vec::new(),
+            EnumVariantKind::Struct(fields) => {
+                fields.iter().filter(|field| !field.phantom).collect()
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    variant.span,
+                    "only unit and struct variants are allowed",
+                ))
+            }
+        };
+        let elm_name = variant.name_elm();
+
+        // Url.Parser ignores empty segments, so a trailing slash doesn't change the route,
+        // but the empty segments are kept in the path to match the paths built by ElmPath
+        let mut segment_parsers = Vec::new();
+        let mut segment_values = Vec::new();
+        let mut arguments = Vec::new();
+        for segment in parse_path_template(path)? {
+            match segment {
+                PathSegment::Parameter(name) => {
+                    let field = fields
+                        .iter()
+                        .find(|field| {
+                            variant.field_name_encode(field, &container_attributes) == name
+                        })
+                        .ok_or_else(|| {
+                            syn::Error::new(
+                                path.span(),
+                                format!("no field for the path parameter `{name}`"),
+                            )
+                        })?;
+                    let ty = &field.ty;
+                    let field_name = field.name_elm();
+                    let tipe = field.ident.to_string().to_uppercase();
+                    // Url.Parser doesn't decode the segments
+                    segment_parsers.push(quote! {
+                        ::std::format!(
+                            "Url.Parser.custom \"{}\" (Url.percentDecode >> Maybe.andThen {})",
+                            #tipe,
+                            <#ty as ::elm_rs::ElmQueryField>::query_field_parser(),
+                        )
+                    });
+                    segment_values.push(quote! {{
+                        ::elm_rs::QueryField::check_path_parameter::<#ty>(#name);
+                        ::std::format!(
+                            "Url.percentEncode ({} struct.{})",
+                            <#ty as ::elm_rs::ElmQueryField>::query_field_encoder(),
+                            #field_name,
+                        )
+                    }});
+                    arguments.push((
+                        field_name.clone(),
+                        format!("elmRs{}", field_name.to_pascal_case()),
+                    ));
+                }
+                PathSegment::Literal(segment) => {
+                    let empty = segment.is_empty();
+                    let segment = format!("{segment:?}");
+                    if !empty {
+                        let segment_parser = format!("Url.Parser.s {segment}");
+                        segment_parsers
+                            .push(quote! { ::std::borrow::ToOwned::to_owned(#segment_parser) });
+                    }
+                    segment_values.push(quote! { ::std::borrow::ToOwned::to_owned(#segment) });
+                }
+            }
+        }
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !arguments.iter().any(|(name, _)| *name == field.name_elm()))
+        {
+            return Err(syn::Error::new(
+                field.ident.span(),
+                "the field is not used in the path of the route",
+            ));
+        }
+
+        let (constructor, pattern) = match &variant.variant {
+            EnumVariantKind::Unit => (elm_name.to_string(), elm_name.to_string()),
+            _ if arguments.is_empty() => (format!("({elm_name} {{}})"), format!("{elm_name} _")),
+            _ => (
+                format!(
+                    "(\\{} -> {elm_name} {{ {} }})",
+                    arguments
+                        .iter()
+                        .map(|(_, argument)| argument.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    arguments
+                        .iter()
+                        .map(|(field_name, argument)| format!("{field_name} = {argument}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                format!("{elm_name} struct"),
+            ),
+        };
+        parsers.push(quote! {{
+            let segment_parsers: &[::std::string::String] = &[#(#segment_parsers),*];
+            let parser = if segment_parsers.is_empty() {
+                ::std::borrow::ToOwned::to_owned("Url.Parser.top")
+            } else {
+                segment_parsers.join(" </> ")
+            };
+            ::std::format!("Url.Parser.map {} ({})", #constructor, parser)
+        }});
+        branches.push(quote! {{
+            let segment_values: &[::std::string::String] = &[#(#segment_values),*];
+            let segments = if segment_values.is_empty() {
+                ::std::borrow::ToOwned::to_owned("[]")
+            } else {
+                ::std::format!("[ {} ]", segment_values.join(", "))
+            };
+            ::std::format!(
+                "{} ->\n            Url.Builder.absolute {} []",
+                #pattern,
+                segments,
+            )
+        }});
+    }
+
+    for p in generics.type_params_mut() {
+        p.bounds.push(syn::parse_str("::elm_rs::Elm").unwrap());
+        p.bounds
+            .push(syn::parse_str("::elm_rs::ElmQueryField").unwrap());
+    }
+
+    let res = quote! {
+        impl #generics ::elm_rs::ElmRoute for #ident #generics_without_bounds {
+            fn elm_route() -> ::std::string::String {
+                ::std::format!("\
+{route_parser_type} : Url.Parser.Parser ({annotation} -> a) a
+{route_parser_type} =
+    Url.Parser.oneOf
+        [ {parsers}
+        ]
+
+
+{route_to_string_type} : {annotation} -> String
+{route_to_string_type} enum =
+    case enum of
+        {branches}
+",
+                    route_parser_type = #route_parser_type,
+                    route_to_string_type = #route_to_string_type,
+                    annotation = #annotation,
+                    parsers = [#(#parsers),*].join("\n        , "),
+                    branches = [#(#branches),*].join("\n\n        "),
+                )
+            }
+
+            fn route_parser_type() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#route_parser_type)
+            }
+
+            fn route_to_string_type() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#route_to_string_type)
+            }
+        }
+    };
+    Ok(res)
+}
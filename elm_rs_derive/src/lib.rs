@@ -7,55 +7,100 @@ mod elm_decode;
 #[cfg(feature = "json")]
 mod elm_encode;
 #[cfg(feature = "query")]
+mod elm_form;
+#[cfg(feature = "json")]
+mod elm_map_key;
+#[cfg(feature = "query")]
+mod elm_path;
+#[cfg(feature = "query")]
 mod elm_query;
 #[cfg(feature = "query")]
 mod elm_query_field;
+#[cfg(feature = "query")]
+mod elm_query_parser;
+#[cfg(feature = "query")]
+mod elm_route;
 
 use self::attributes::{ContainerAttributes, FieldAttributes, VariantAttributes};
 use heck::{ToLowerCamelCase, ToPascalCase};
 use proc_macro::TokenStream;
-use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
 use quote::ToTokens;
 use std::borrow::Cow;
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, Data, DataEnum, DeriveInput, Fields, FieldsNamed,
-    Generics, Ident, Type, Variant,
+    punctuated::Punctuated, spanned::Spanned, Data, DataEnum, DeriveInput, Field, Fields,
+    FieldsNamed, Generics, Ident, LitStr, Type, Variant,
 };
 
 /// Derive `Elm`.
-#[proc_macro_derive(Elm)]
+#[proc_macro_derive(Elm, attributes(elm))]
 pub fn derive_elm(input: TokenStream) -> TokenStream {
     elm::derive(input)
 }
 
 /// Derive `ElmEncode`.
 #[cfg(feature = "json")]
-#[proc_macro_derive(ElmEncode)]
+#[proc_macro_derive(ElmEncode, attributes(elm))]
 pub fn derive_elm_serialize(input: TokenStream) -> TokenStream {
     elm_encode::derive(input)
 }
 
 /// Derive `ElmDecode`.
 #[cfg(feature = "json")]
-#[proc_macro_derive(ElmDecode)]
+#[proc_macro_derive(ElmDecode, attributes(elm))]
 pub fn derive_elm_deserialize(input: TokenStream) -> TokenStream {
     elm_decode::derive(input)
 }
 
+/// Derive `ElmMapKey`.
+#[cfg(feature = "json")]
+#[proc_macro_derive(ElmMapKey, attributes(elm))]
+pub fn derive_elm_map_key(input: TokenStream) -> TokenStream {
+    elm_map_key::derive(input)
+}
+
+/// Derive `ElmForm`.
+#[cfg(feature = "query")]
+#[proc_macro_derive(ElmForm, attributes(elm))]
+pub fn derive_elm_form(input: TokenStream) -> TokenStream {
+    elm_form::derive(input)
+}
+
+/// Derive `ElmPath`.
+#[cfg(feature = "query")]
+#[proc_macro_derive(ElmPath, attributes(elm))]
+pub fn derive_elm_path(input: TokenStream) -> TokenStream {
+    elm_path::derive(input)
+}
+
 /// Derive `ElmQuery`.
 #[cfg(feature = "query")]
-#[proc_macro_derive(ElmQuery)]
+#[proc_macro_derive(ElmQuery, attributes(elm))]
 pub fn derive_elm_query(input: TokenStream) -> TokenStream {
     elm_query::derive(input)
 }
 
 /// Derive `ElmQueryField`.
 #[cfg(feature = "query")]
-#[proc_macro_derive(ElmQueryField)]
+#[proc_macro_derive(ElmQueryField, attributes(elm))]
 pub fn derive_elm_query_field(input: TokenStream) -> TokenStream {
     elm_query_field::derive(input)
 }
 
+/// Derive `ElmQueryParser`.
+#[cfg(feature = "query")]
+#[proc_macro_derive(ElmQueryParser, attributes(elm))]
+pub fn derive_elm_query_parser(input: TokenStream) -> TokenStream {
+    elm_query_parser::derive(input)
+}
+
+/// Derive `ElmRoute`.
+#[cfg(feature = "query")]
+#[proc_macro_derive(ElmRoute, attributes(elm))]
+pub fn derive_elm_route(input: TokenStream) -> TokenStream {
+    elm_route::derive(input)
+}
+
 /// Intermediate representation of the derive input for more convenient handling.
 struct Intermediate {
     ident: Ident,
@@ -64,12 +109,26 @@ struct Intermediate {
     generics_without_bounds: Generics,
     type_info: TypeInfo,
     container_attributes: ContainerAttributes,
+    // type parameters that become phantom type variables in Elm with #[elm(phantom)]
+    phantom_params: Vec<Ident>,
 }
 
 impl Intermediate {
     // parses the input to an intermediate representation that's convenient to turn into the end result
     fn parse(input: DeriveInput) -> syn::Result<Self> {
         let container_attributes = ContainerAttributes::parse(&input.attrs)?;
+        let phantom_params = if container_attributes.elm.phantom {
+            let phantom_params = phantom_params(&input);
+            if phantom_params.is_empty() {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "#[elm(phantom)] requires a type parameter that is only used in PhantomData fields",
+                ));
+            }
+            phantom_params
+        } else {
+            Vec::new()
+        };
         let type_info = TypeInfo::parse(input.data, &container_attributes)?;
 
         let elm_type = input.ident.to_string().to_pascal_case();
@@ -84,10 +143,71 @@ impl Intermediate {
             generics_without_bounds,
             type_info,
             container_attributes,
+            phantom_params,
         })
     }
 }
 
+/// The type parameters that are only used inside `PhantomData` fields.
+fn phantom_params(input: &DeriveInput) -> Vec<Ident> {
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    let (phantom_fields, other_fields): (Vec<&Field>, Vec<&Field>) = fields
+        .into_iter()
+        .partition(|field| is_phantom_data(&field.ty));
+    input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .filter(|ident| {
+            phantom_fields
+                .iter()
+                .any(|field| contains_ident(field.ty.to_token_stream(), ident))
+                && !other_fields
+                    .iter()
+                    .any(|field| contains_ident(field.ty.to_token_stream(), ident))
+        })
+        .cloned()
+        .collect()
+}
+
+/// The Elm type variables for the phantom type parameters, e.g. " t".
+fn phantom_type_variables(phantom_params: &[Ident]) -> String {
+    phantom_params
+        .iter()
+        .map(|param| format!(" {}", param.to_string().to_lower_camel_case()))
+        .collect()
+}
+
+/// `PhantomData` is serialized as a unit and skipped in Elm records.
+fn is_phantom_data(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "PhantomData")
+            .unwrap_or_default()
+    } else {
+        false
+    }
+}
+
+fn contains_ident(tokens: TokenStream2, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token_ident) => &token_ident == ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
 enum TypeInfo {
     // struct S;
     Unit,
@@ -116,9 +236,17 @@ impl TypeInfo {
                 Fields::Unit => TypeInfo::Unit,
                 Fields::Unnamed(unnamed) => {
                     if unnamed.unnamed.len() == 1 {
-                        TypeInfo::Newtype(Box::new(unnamed.unnamed.into_iter().next().unwrap().ty))
+                        TypeInfo::Newtype(Box::new(unnamed_field_type(
+                            unnamed.unnamed.into_iter().next().unwrap(),
+                        )?))
                     } else {
-                        TypeInfo::Tuple(unnamed.unnamed.into_iter().map(|field| field.ty).collect())
+                        TypeInfo::Tuple(
+                            unnamed
+                                .unnamed
+                                .into_iter()
+                                .map(unnamed_field_type)
+                                .collect::<syn::Result<_>>()?,
+                        )
                     }
                 }
                 Fields::Named(named) => {
@@ -126,8 +254,15 @@ impl TypeInfo {
                     let transparent = false;
                     #[cfg(feature = "serde")]
                     let transparent = container_attributes.serde.transparent;
-                    if transparent && named.named.len() == 1 {
-                        TypeInfo::Newtype(Box::new(named.named.into_iter().next().unwrap().ty))
+                    // transparent structs may contain PhantomData fields in addition to the inner type
+                    let mut non_phantom = named
+                        .named
+                        .iter()
+                        .filter(|field| !is_phantom_data(&field.ty));
+                    if let (true, Some(inner), None) =
+                        (transparent, non_phantom.next(), non_phantom.next())
+                    {
+                        TypeInfo::Newtype(Box::new(unnamed_field_type(inner.clone())?))
                     } else {
                         TypeInfo::Struct(StructField::parse(named)?)
                     }
@@ -167,6 +302,12 @@ struct StructField {
     // todo
     // aliases: Vec<String>,
     ty: TokenStream2,
+    // PhantomData fields are not included in the Elm record and are encoded as null
+    phantom: bool,
+    // #[elm(query_style = "..")]
+    query_style: Option<attributes::elm::QueryStyle>,
+    // #[elm(query)]
+    query: bool,
     #[cfg(feature = "serde")]
     serde_attributes: attributes::serde::FieldAttributes,
 }
@@ -227,6 +368,58 @@ impl StructField {
         self.ident.to_string()
     }
 
+    /// Whether a missing value is deserialized as a default value because of `#[serde(default)]` on the field
+    /// or the container. For containers, the `Default` implementation is assumed to use the default values
+    /// of the fields like the derived one does.
+    #[cfg(feature = "query")]
+    fn default(&self, container_attributes: &ContainerAttributes) -> bool {
+        #[cfg(feature = "serde")]
+        return self.serde_attributes.default || container_attributes.serde.default;
+        #[cfg(not(feature = "serde"))]
+        {
+            let _ = container_attributes;
+            false
+        }
+    }
+
+    /// Whether the query style is `#[elm(query_style = "comma")]`.
+    #[cfg(feature = "query")]
+    fn comma_separated(&self) -> bool {
+        self.query_style == Some(attributes::elm::QueryStyle::Comma)
+    }
+
+    /// Whether the fields of the field's type are nested under its key because of
+    /// `#[elm(query_style = "brackets")]`.
+    #[cfg(feature = "query")]
+    fn brackets(&self) -> bool {
+        self.query_style == Some(attributes::elm::QueryStyle::Brackets)
+    }
+
+    /// A statement that reports an error when the Elm code is generated if the field has a list query style
+    /// but isn't a list.
+    #[cfg(feature = "query")]
+    fn query_style_check(&self) -> TokenStream2 {
+        let query_style = match self.query_style {
+            Some(attributes::elm::QueryStyle::Repeated) => "repeated",
+            Some(attributes::elm::QueryStyle::Comma) => "comma",
+            _ => return TokenStream2::new(),
+        };
+        let ty = &self.ty;
+        let field = self.ident.to_string();
+        quote::quote! {
+            ::elm_rs::QueryField::check_list_style::<#ty>(#field, #query_style);
+        }
+    }
+
+    /// Whether the fields of the field's type are inlined into the parent because of `#[serde(flatten)]`.
+    #[cfg(feature = "query")]
+    fn flatten(&self) -> bool {
+        #[cfg(feature = "serde")]
+        return self.serde_attributes.flatten;
+        #[cfg(not(feature = "serde"))]
+        false
+    }
+
     fn parse(fields: FieldsNamed) -> syn::Result<Vec<Self>> {
         let mut parsed = Vec::new();
         for field in fields.named {
@@ -235,11 +428,16 @@ impl StructField {
             if attributes.serde.skip {
                 continue;
             }
+            let ty = field_type(&field, &attributes)?;
+            let phantom = is_phantom_data(&field.ty);
             parsed.push(StructField {
                 ident: field.ident.unwrap(), // only tuple struct fields are unnamed
                 // todo
                 // aliases: field_attributes.serde.aliases,
-                ty: field.ty.to_token_stream(),
+                ty,
+                phantom,
+                query_style: attributes.elm.query_style,
+                query: attributes.elm.query,
                 #[cfg(feature = "serde")]
                 serde_attributes: attributes.serde,
             });
@@ -248,10 +446,120 @@ impl StructField {
     }
 }
 
+/// The type used for the Elm code of a named or unnamed field, taking `#[serde(with = "..")]` and
+/// `#[elm(large_integers = "..")]` into account.
+fn field_type(field: &Field, attributes: &FieldAttributes) -> syn::Result<TokenStream2> {
+    #[cfg(feature = "serde")]
+    let ty = match attributes.serde.with.as_deref() {
+        Some(with) => with_type(with, field)?,
+        None => None,
+    }
+    .unwrap_or_else(|| field.ty.to_token_stream());
+    #[cfg(not(feature = "serde"))]
+    let ty = field.ty.to_token_stream();
+    Ok(elm_field_type(ty, attributes))
+}
+
+/// Fields with `#[elm(large_integers = "..")]` are wrapped so that their Elm code is generated with the given policy.
+fn elm_field_type(ty: TokenStream2, attributes: &FieldAttributes) -> TokenStream2 {
+    match &attributes.elm.large_integers {
+        Some(policy) => {
+            quote::quote!(::elm_rs::WithLargeIntegers<#ty, ::elm_rs::large_integers::#policy>)
+        }
+        None => ty,
+    }
+}
+
+fn unnamed_field_type(field: Field) -> syn::Result<Type> {
+    let attributes = FieldAttributes::parse(&field.attrs)?;
+    syn::parse2(field_type(&field, &attributes)?)
+}
+
+/// Some commonly used `#[serde(with = "..")]` modules change the representation of the field.
+/// For the known modules, returns a type with the same representation. Other modules are assumed to keep
+/// the representation of the field's type, except for unknown modules of `time::serde` and `chrono::serde`
+/// which are rejected.
+#[cfg(feature = "serde")]
+fn with_type(with: &str, field: &Field) -> syn::Result<Option<TokenStream2>> {
+    let path = with.trim_start_matches("::");
+    let ty = match path {
+        "time::serde::rfc3339" => quote::quote!(::elm_rs::posix::Rfc3339),
+        "time::serde::rfc3339::option" => {
+            quote::quote!(::std::option::Option<::elm_rs::posix::Rfc3339>)
+        }
+        "time::serde::rfc2822" | "time::serde::iso8601" => {
+            quote::quote!(::std::string::String)
+        }
+        "time::serde::rfc2822::option" | "time::serde::iso8601::option" => {
+            quote::quote!(::std::option::Option<::std::string::String>)
+        }
+        "time::serde::timestamp" | "chrono::serde::ts_seconds" => {
+            quote::quote!(::elm_rs::posix::Timestamp<::elm_rs::posix::Seconds>)
+        }
+        "time::serde::timestamp::milliseconds_i64" | "chrono::serde::ts_milliseconds" => {
+            quote::quote!(::elm_rs::posix::Timestamp<::elm_rs::posix::Milliseconds>)
+        }
+        "chrono::serde::ts_microseconds" => {
+            quote::quote!(::elm_rs::posix::Timestamp<::elm_rs::posix::Microseconds>)
+        }
+        "chrono::serde::ts_nanoseconds" => {
+            quote::quote!(::elm_rs::posix::Timestamp<::elm_rs::posix::Nanoseconds>)
+        }
+        // time serializes these timestamps as i128
+        "time::serde::timestamp::milliseconds" => quote::quote!(
+            ::elm_rs::posix::Timestamp<::elm_rs::posix::Milliseconds, ::std::primitive::i128>
+        ),
+        "time::serde::timestamp::microseconds" => quote::quote!(
+            ::elm_rs::posix::Timestamp<::elm_rs::posix::Microseconds, ::std::primitive::i128>
+        ),
+        "time::serde::timestamp::nanoseconds" => quote::quote!(
+            ::elm_rs::posix::Timestamp<::elm_rs::posix::Nanoseconds, ::std::primitive::i128>
+        ),
+        "time::serde::timestamp::option" | "chrono::serde::ts_seconds_option" => quote::quote!(
+            ::std::option::Option<::elm_rs::posix::Timestamp<::elm_rs::posix::Seconds>>
+        ),
+        "time::serde::timestamp::milliseconds_i64::option"
+        | "chrono::serde::ts_milliseconds_option" => quote::quote!(
+            ::std::option::Option<::elm_rs::posix::Timestamp<::elm_rs::posix::Milliseconds>>
+        ),
+        "chrono::serde::ts_microseconds_option" => quote::quote!(
+            ::std::option::Option<::elm_rs::posix::Timestamp<::elm_rs::posix::Microseconds>>
+        ),
+        "chrono::serde::ts_nanoseconds_option" => quote::quote!(
+            ::std::option::Option<::elm_rs::posix::Timestamp<::elm_rs::posix::Nanoseconds>>
+        ),
+        "time::serde::timestamp::milliseconds::option" => quote::quote!(
+            ::std::option::Option<
+                ::elm_rs::posix::Timestamp<::elm_rs::posix::Milliseconds, ::std::primitive::i128>,
+            >
+        ),
+        "time::serde::timestamp::microseconds::option" => quote::quote!(
+            ::std::option::Option<
+                ::elm_rs::posix::Timestamp<::elm_rs::posix::Microseconds, ::std::primitive::i128>,
+            >
+        ),
+        "time::serde::timestamp::nanoseconds::option" => quote::quote!(
+            ::std::option::Option<
+                ::elm_rs::posix::Timestamp<::elm_rs::posix::Nanoseconds, ::std::primitive::i128>,
+            >
+        ),
+        _ if path.starts_with("time::serde::") || path.starts_with("chrono::serde::") => {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("unsupported serde module `{with}`"),
+            ))
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(ty))
+}
+
 struct EnumVariant {
     ident: Ident,
     variant: EnumVariantKind,
     span: Span,
+    // #[elm(path = "..")]
+    path: Option<LitStr>,
     #[cfg(feature = "serde")]
     serde_attributes: attributes::serde::VariantAttributes,
 }
@@ -312,26 +620,52 @@ impl EnumVariant {
         self.ident.to_string()
     }
 
+    /// The name of a field of the variant during Elm encoding. The fields of struct variants are renamed by
+    /// the variant's `rename_all` or the container's `rename_all_fields` instead of the container's `rename_all`.
+    #[cfg(feature = "query")]
+    fn field_name_encode(
+        &self,
+        field: &StructField,
+        container_attributes: &ContainerAttributes,
+    ) -> String {
+        // explicit rename has priority
+        #[cfg(feature = "serde")]
+        if let Some(rename) = field
+            .serde_attributes
+            .rename
+            .as_ref()
+            .or(field.serde_attributes.rename_deserialize.as_ref())
+        {
+            rename.clone()
+        } else if let Some(rename_all) = self
+            .serde_attributes
+            .rename_all
+            .or(self.serde_attributes.rename_all_deserialize)
+            .or(container_attributes.serde.rename_all_fields)
+            .or(container_attributes.serde.rename_all_fields_deserialize)
+        {
+            rename_all.rename_ident(&field.ident)
+        } else {
+            field.ident.to_string()
+        }
+        #[cfg(not(feature = "serde"))]
+        field.ident.to_string()
+    }
+
     fn parse(variant: Variant) -> syn::Result<Self> {
         let span = variant.span();
         let variant_attributes = VariantAttributes::parse(&variant.attrs)?;
         let variant_kind = match variant.fields {
             Fields::Unit => EnumVariantKind::Unit,
             Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => EnumVariantKind::Newtype(
-                unnamed
-                    .unnamed
-                    .into_iter()
-                    .next()
-                    .unwrap()
-                    .ty
-                    .to_token_stream(),
+                unnamed_field_type(unnamed.unnamed.into_iter().next().unwrap())?.to_token_stream(),
             ),
             Fields::Unnamed(unnamed) => EnumVariantKind::Tuple(
                 unnamed
                     .unnamed
                     .into_iter()
-                    .map(|field| field.ty.to_token_stream())
-                    .collect(),
+                    .map(|field| Ok(unnamed_field_type(field)?.to_token_stream()))
+                    .collect::<syn::Result<_>>()?,
             ),
             Fields::Named(named) => EnumVariantKind::Struct(StructField::parse(named)?),
         };
@@ -339,6 +673,7 @@ impl EnumVariant {
             ident: variant.ident,
             variant: variant_kind,
             span,
+            path: variant_attributes.elm.path,
             #[cfg(feature = "serde")]
             serde_attributes: variant_attributes.serde,
         };